@@ -16,8 +16,8 @@ fn gl_error<C: glow::HasContext>(gl: &C, str: &str) {
 const MAX_VERTEX_MEMORY: i32 = 512 * 1024;
 const MAX_ELEMENT_MEMORY: i32 = 128 * 1024;
 
-pub fn demo_window(nk_context: &mut nukly::Nuklear) {
-    nk_context.begin(
+pub fn demo_window(ui: &mut nukly::NuklearScope<nukly::draw::UiScope>) {
+    ui.begin(
         "Balls",
         (50.0, 50.0, 220.0, 500.0),
         nukly::draw::PanelFlags::BORDER
@@ -26,8 +26,12 @@ pub fn demo_window(nk_context: &mut nukly::Nuklear) {
         |ctx| {
             ctx.layout_row_static(30.0, 80.0, 1);
             ctx.button_label("FUCK MY LIFE LOL");
+
+            ctx.layout_row_dynamic(20.0, 1);
+            ctx.label("Hello, nukly!", nukly::text::TextAlign::MIDDLE_LEFT);
         },
     )
+    .unwrap();
 }
 
 #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen(start))]
@@ -247,8 +251,10 @@ fn main() {
                 texture as usize
             });
 
-        let mut nk_context = nukly::Nuklear::create(allocator, &image.atlas().fonts()[0]).unwrap();
-        let mut event_handler = NuklyWindowEventHandler::default();
+        let mut nk_context =
+            nukly::Nuklear::create(allocator, std::sync::Arc::new(image), 0).unwrap();
+        let mut event_handler = NuklyWindowEventHandler::default()
+            .with_scale_factor(windowed_context.window().scale_factor());
 
         let texture_loc = gl.get_uniform_location(program, "Texture");
         let proj_loc = gl.get_uniform_location(program, "ProjMtx");
@@ -261,9 +267,10 @@ fn main() {
             event_loop.run(move |event, _, control_flow| {
                 *control_flow = ControlFlow::Poll;
 
-                //let input = nk_context.begin_input();
-                event_handler.handle_event(&mut nk_context, &event);
-                //nk_context = input.end();
+                if event_handler.handle_event(&nk_context, &event) {
+                    // The UI consumed this event, or the press this event releases
+                    return;
+                }
 
                 match event {
                     Event::LoopDestroyed => {
@@ -273,10 +280,27 @@ fn main() {
                         windowed_context.window().request_redraw();
                     }
                     Event::RedrawRequested(_) => {
-                        nk_context.end_input();
-                        demo_window(&mut nk_context);
+                        event_handler.finish(&mut nk_context, windowed_context.window());
+
+                        let mut ui = nk_context.frame();
+                        demo_window(&mut ui);
+                        let mut frame = ui.finish();
+
+                        // Nuklear works in logical coordinates, while the viewport and scissor
+                        // rectangles are in physical pixels.
+                        let scale = event_handler.scale_factor() as f32;
+                        let physical_size = windowed_context.window().inner_size();
+                        let (width, height) = (
+                            physical_size.width as f32 / scale,
+                            physical_size.height as f32 / scale,
+                        );
 
-                        gl.viewport(0, 0, 1024, 768);
+                        gl.viewport(
+                            0,
+                            0,
+                            physical_size.width as i32,
+                            physical_size.height as i32,
+                        );
 
                         gl.clear_color(0.2, 0.2, 0.2, 1.0);
 
@@ -300,11 +324,12 @@ fn main() {
 
                         gl.uniform_1_i32(texture_loc.as_ref(), 0);
 
-                        //#[rustfmt::skip]
+                        #[rustfmt::skip]
                         let ortho = [
-                            0.001667, 0.000000, 0.000000, 0.000000, 0.000000, -0.002500, 0.000000,
-                            0.000000, 0.000000, 0.000000, -1.000000, 0.000000, -1.000000, 1.000000,
-                            0.000000, 1.000000,
+                            2.0 / width, 0.0, 0.0, 0.0,
+                            0.0, -2.0 / height, 0.0, 0.0,
+                            0.0, 0.0, -1.0, 0.0,
+                            -1.0, 1.0, 0.0, 1.0,
                         ];
                         gl.uniform_matrix_4_f32_slice(proj_loc.as_ref(), false, &ortho);
 
@@ -338,23 +363,23 @@ fn main() {
                                 MAX_VERTEX_MEMORY as usize,
                             );
 
-                            let prepare = nk_context
-                                .prepare_draw(vertices_slice, elements_slice)
-                                .unwrap();
+                            let prepare =
+                                frame.prepare_draw(vertices_slice, elements_slice).unwrap();
 
                             gl.unmap_buffer(glow::ARRAY_BUFFER);
                             gl.unmap_buffer(glow::ELEMENT_ARRAY_BUFFER);
 
                             let mut offset = 0;
-                            nk_context
+                            frame
                                 .draw(prepare, |ctx, cmd| {
                                     gl.bind_texture(glow::TEXTURE_2D, Some(cmd.texture.id as u32));
 
                                     gl.scissor(
-                                        cmd.clip_rect.x as i32,
-                                        (768.0 - (cmd.clip_rect.y + cmd.clip_rect.h)) as i32,
-                                        cmd.clip_rect.w as i32,
-                                        cmd.clip_rect.h as i32,
+                                        (cmd.clip_rect.x * scale) as i32,
+                                        ((height - (cmd.clip_rect.y + cmd.clip_rect.h)) * scale)
+                                            as i32,
+                                        (cmd.clip_rect.w * scale) as i32,
+                                        (cmd.clip_rect.h * scale) as i32,
                                     );
 
                                     gl.draw_elements(
@@ -375,8 +400,9 @@ fn main() {
 
                         gl.bind_vertex_array(None);
 
+                        drop(frame);
+
                         windowed_context.swap_buffers().unwrap();
-                        nk_context.begin_input();
                     }
                     Event::WindowEvent { ref event, .. } => match event {
                         WindowEvent::Resized(physical_size) => {
@@ -116,6 +116,12 @@ pub mod global {
             this
         }
 
+        #[cfg(feature = "alloc-counters")]
+        #[inline]
+        pub fn counters(&self) -> &Counters {
+            &self.counters
+        }
+
         // This is safe because we only use it once to set our inner value AFTER pinning
         #[allow(clippy::cast_ref_to_mut)] // TODO: fix this later
         fn set_inner_ptr(&self) {
@@ -0,0 +1,158 @@
+use crate::{
+    color::Color,
+    draw::{Image, Symbol, WindowScope},
+    sys,
+    text::TextAlign,
+    text_ptr, NuklearScope, NuklearType,
+};
+
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ButtonBehavior {
+    /// The button is pressed once when clicked
+    Default = sys::nk_button_behavior_NK_BUTTON_DEFAULT,
+    /// The button is pressed every frame it is held down
+    Repeater = sys::nk_button_behavior_NK_BUTTON_REPEATER,
+}
+impl Into<sys::nk_button_behavior> for ButtonBehavior {
+    fn into(self) -> sys::nk_button_behavior {
+        self as sys::nk_button_behavior
+    }
+}
+
+impl<'a> NuklearScope<'a, WindowScope> {
+    pub fn button_label<S>(&self, label: S) -> bool
+    where
+        S: AsRef<str>,
+    {
+        let (label, len) = text_ptr(label.as_ref());
+        unsafe { sys::nk_button_text(self.as_ptr(), label, len) != 0 }
+    }
+
+    pub fn button_color<C>(&self, color: C) -> bool
+    where
+        C: Into<Color>,
+    {
+        unsafe { sys::nk_button_color(self.as_ptr(), color.into().into()) != 0 }
+    }
+
+    pub fn button_symbol(&self, symbol: Symbol) -> bool {
+        unsafe { sys::nk_button_symbol(self.as_ptr(), symbol.into()) != 0 }
+    }
+
+    pub fn button_symbol_label<S>(&self, symbol: Symbol, label: S, align: TextAlign) -> bool
+    where
+        S: AsRef<str>,
+    {
+        let (label, len) = text_ptr(label.as_ref());
+        unsafe {
+            sys::nk_button_symbol_text(self.as_ptr(), symbol.into(), label, len, align.bits()) != 0
+        }
+    }
+
+    pub fn button_image(&self, image: Image) -> bool {
+        unsafe { sys::nk_button_image(self.as_ptr(), image.into()) != 0 }
+    }
+
+    pub fn button_image_label<S>(&self, image: Image, label: S, align: TextAlign) -> bool
+    where
+        S: AsRef<str>,
+    {
+        let (label, len) = text_ptr(label.as_ref());
+        unsafe {
+            sys::nk_button_image_text(self.as_ptr(), image.into(), label, len, align.bits()) != 0
+        }
+    }
+
+    /// Like [`NuklearScope::button_label`], drawn with `style` instead of the context's button
+    /// style. See [`crate::Nuklear::button_style`] for a style to start from.
+    pub fn button_label_styled<S>(&self, style: &sys::nk_style_button, label: S) -> bool
+    where
+        S: AsRef<str>,
+    {
+        let (label, len) = text_ptr(label.as_ref());
+        unsafe { sys::nk_button_text_styled(self.as_ptr(), style, label, len) != 0 }
+    }
+
+    pub fn button_symbol_styled(&self, style: &sys::nk_style_button, symbol: Symbol) -> bool {
+        unsafe { sys::nk_button_symbol_styled(self.as_ptr(), style, symbol.into()) != 0 }
+    }
+
+    pub fn button_symbol_label_styled<S>(
+        &self,
+        style: &sys::nk_style_button,
+        symbol: Symbol,
+        label: S,
+        align: TextAlign,
+    ) -> bool
+    where
+        S: AsRef<str>,
+    {
+        let (label, len) = text_ptr(label.as_ref());
+        unsafe {
+            sys::nk_button_symbol_text_styled(
+                self.as_ptr(),
+                style,
+                symbol.into(),
+                label,
+                len,
+                align.bits(),
+            ) != 0
+        }
+    }
+
+    pub fn button_image_styled(&self, style: &sys::nk_style_button, image: Image) -> bool {
+        unsafe { sys::nk_button_image_styled(self.as_ptr(), style, image.into()) != 0 }
+    }
+
+    pub fn button_image_label_styled<S>(
+        &self,
+        style: &sys::nk_style_button,
+        image: Image,
+        label: S,
+        align: TextAlign,
+    ) -> bool
+    where
+        S: AsRef<str>,
+    {
+        let (label, len) = text_ptr(label.as_ref());
+        unsafe {
+            sys::nk_button_image_text_styled(
+                self.as_ptr(),
+                style,
+                image.into(),
+                label,
+                len,
+                align.bits(),
+            ) != 0
+        }
+    }
+
+    /// Sets the behavior of all buttons that follow.
+    pub fn button_set_behavior(&self, behavior: ButtonBehavior) {
+        unsafe { sys::nk_button_set_behavior(self.as_ptr(), behavior.into()) }
+    }
+
+    /// Sets the behavior of the buttons that follow, until the matching
+    /// [`NuklearScope::button_pop_behavior`]. Returns `false` if the behavior stack is full.
+    pub fn button_push_behavior(&self, behavior: ButtonBehavior) -> bool {
+        unsafe { sys::nk_button_push_behavior(self.as_ptr(), behavior.into()) != 0 }
+    }
+
+    /// Restores the behavior from before the last push. Returns `false` if nothing was pushed.
+    pub fn button_pop_behavior(&self) -> bool {
+        unsafe { sys::nk_button_pop_behavior(self.as_ptr()) != 0 }
+    }
+
+    /// Calls `f` with the behavior of its buttons set to `behavior`.
+    pub fn with_button_behavior<F>(&self, behavior: ButtonBehavior, f: F)
+    where
+        F: FnOnce(&Self),
+    {
+        let pushed = self.button_push_behavior(behavior);
+        (f)(self);
+        if pushed {
+            self.button_pop_behavior();
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use crate::{draw::WindowScope, sys, text_ptr, NuklearScope, NuklearType};
+use std::os::raw::c_int;
+
+/// A type with a fixed set of values to pick from with [`NuklearScope::options`], usually a
+/// fieldless enum.
+pub trait Options: PartialEq + Copy + 'static {
+    /// Every value, in the order they are laid out
+    const ALL: &'static [Self];
+
+    fn label(&self) -> &str;
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum OptionsLayout {
+    /// All options side by side in a single row
+    Row,
+    /// One option per row
+    Column,
+}
+
+impl<'a> NuklearScope<'a, WindowScope> {
+    /// A checkbox toggling `active`. Returns whether it was toggled.
+    pub fn checkbox_label<S>(&self, label: S, active: &mut bool) -> bool
+    where
+        S: AsRef<str>,
+    {
+        let (label, len) = text_ptr(label.as_ref());
+        let mut value = c_int::from(*active);
+        let changed = unsafe { sys::nk_checkbox_text(self.as_ptr(), label, len, &mut value) != 0 };
+        *active = value != 0;
+
+        changed
+    }
+
+    /// A checkbox for the bits of `value` in `flags`, which is checked while any of them is set.
+    /// Toggling it sets or clears all of them. Returns whether `flags` changed.
+    pub fn checkbox_flags_label<S>(&self, label: S, flags: &mut u32, value: u32) -> bool
+    where
+        S: AsRef<str>,
+    {
+        let (label, len) = text_ptr(label.as_ref());
+        unsafe { sys::nk_checkbox_flags_text(self.as_ptr(), label, len, flags, value) != 0 }
+    }
+
+    /// A checkbox drawn as `active`. Returns its state after this frame's input.
+    pub fn check_label<S>(&self, label: S, active: bool) -> bool
+    where
+        S: AsRef<str>,
+    {
+        let (label, len) = text_ptr(label.as_ref());
+        unsafe { sys::nk_check_text(self.as_ptr(), label, len, active.into()) != 0 }
+    }
+
+    /// A checkbox for the bits of `value` in `flags`, checked while any of them is set and setting
+    /// or clearing all of them when toggled. Returns `flags` after this frame's input.
+    pub fn check_flags_label<S>(&self, label: S, flags: u32, value: u32) -> u32
+    where
+        S: AsRef<str>,
+    {
+        let (label, len) = text_ptr(label.as_ref());
+        unsafe { sys::nk_check_flags_text(self.as_ptr(), label, len, flags, value) }
+    }
+
+    /// A radio button, which sets `active` when clicked but never clears it. Returns whether it was
+    /// set.
+    pub fn radio_label<S>(&self, label: S, active: &mut bool) -> bool
+    where
+        S: AsRef<str>,
+    {
+        let (label, len) = text_ptr(label.as_ref());
+        let mut value = c_int::from(*active);
+        let changed = unsafe { sys::nk_radio_text(self.as_ptr(), label, len, &mut value) != 0 };
+        *active = value != 0;
+
+        changed
+    }
+
+    /// A radio button drawn as `active`. Returns its state after this frame's input.
+    pub fn option_label<S>(&self, label: S, active: bool) -> bool
+    where
+        S: AsRef<str>,
+    {
+        let (label, len) = text_ptr(label.as_ref());
+        unsafe { sys::nk_option_text(self.as_ptr(), label, len, active.into()) != 0 }
+    }
+
+    /// A radio button for `value`, which is active while `selected` is `value` and selects it when
+    /// clicked. Returns whether `selected` changed.
+    pub fn option_label_value<S, T>(&self, label: S, selected: &mut T, value: T) -> bool
+    where
+        S: AsRef<str>,
+        T: PartialEq,
+    {
+        let active = *selected == value;
+        if self.option_label(label, active) && !active {
+            *selected = value;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// A radio button for every value of `T`, in rows of `height`. Returns whether `selected`
+    /// changed.
+    pub fn options<T>(&self, selected: &mut T, layout: OptionsLayout, height: f32) -> bool
+    where
+        T: Options,
+    {
+        if layout == OptionsLayout::Row {
+            self.layout_row_dynamic(height, T::ALL.len());
+        }
+
+        let mut changed = false;
+        for value in T::ALL {
+            if layout == OptionsLayout::Column {
+                self.layout_row_dynamic(height, 1);
+            }
+            changed |= self.option_label_value(value.label(), selected, *value);
+        }
+
+        changed
+    }
+}
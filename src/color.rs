@@ -0,0 +1,290 @@
+use crate::sys;
+use std::{fmt, str::FromStr};
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum ColorParseError {
+    #[error("A hex color needs 6 or 8 digits, got {0}")]
+    InvalidLength(usize),
+    #[error("'{0}' is not a hex digit")]
+    InvalidDigit(char),
+}
+
+/// An 8-bit per channel RGBA color.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+impl Color {
+    #[inline]
+    pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
+    }
+
+    #[inline]
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self::rgba(r, g, b, 255)
+    }
+
+    pub fn from_hsv(h: u8, s: u8, v: u8) -> Self {
+        Self::from_hsva(h, s, v, 255)
+    }
+
+    pub fn from_hsva(h: u8, s: u8, v: u8, a: u8) -> Self {
+        unsafe { sys::nk_hsva(h.into(), s.into(), v.into(), a.into()) }.into()
+    }
+
+    /// Creates a color from HSV components in the range `0.0..=1.0`.
+    pub fn from_hsv_f(h: f32, s: f32, v: f32) -> Self {
+        Self::from_hsva_f(h, s, v, 1.0)
+    }
+
+    /// Creates a color from HSVA components in the range `0.0..=1.0`.
+    pub fn from_hsva_f(h: f32, s: f32, v: f32, a: f32) -> Self {
+        unsafe { sys::nk_hsva_f(h, s, v, a) }.into()
+    }
+
+    pub fn to_hsv(self) -> (u8, u8, u8) {
+        let (h, s, v, _) = self.to_hsva();
+        (h, s, v)
+    }
+
+    pub fn to_hsva(self) -> (u8, u8, u8, u8) {
+        let mut hsva = [0; 4];
+        unsafe { sys::nk_color_hsva_bv(hsva.as_mut_ptr(), self.into()) };
+        (hsva[0], hsva[1], hsva[2], hsva[3])
+    }
+
+    /// The HSVA components of the color, in the range `0.0..=1.0`.
+    pub fn to_hsva_f(self) -> (f32, f32, f32, f32) {
+        let mut hsva = [0.0; 4];
+        unsafe { sys::nk_color_hsva_fv(hsva.as_mut_ptr(), self.into()) };
+        (hsva[0], hsva[1], hsva[2], hsva[3])
+    }
+
+    /// Parses a color from `RRGGBB` or `RRGGBBAA` hex digits, optionally prefixed with `#`.
+    pub fn from_hex(hex: &str) -> Result<Self, ColorParseError> {
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+
+        let mut channels = [255_u8; 4];
+        let digits = hex.chars().count();
+        if digits != 6 && digits != 8 {
+            return Err(ColorParseError::InvalidLength(digits));
+        }
+
+        let mut chars = hex.chars();
+        for channel in channels.iter_mut().take(digits / 2) {
+            let mut value = 0;
+            for c in chars.by_ref().take(2) {
+                #[allow(clippy::cast_possible_truncation)]
+                let digit = c.to_digit(16).ok_or(ColorParseError::InvalidDigit(c))? as u8;
+                value = value * 16 + digit;
+            }
+            *channel = value;
+        }
+
+        Ok(channels.into())
+    }
+
+    /// The color as `#RRGGBB` hex digits.
+    pub fn to_hex_rgb(self) -> String {
+        format!("#{:02X}{:02X}{:02X}", self.r, self.g, self.b)
+    }
+
+    /// The color as `#RRGGBBAA` hex digits.
+    pub fn to_hex_rgba(self) -> String {
+        format!("#{:02X}{:02X}{:02X}{:02X}", self.r, self.g, self.b, self.a)
+    }
+}
+impl FromStr for Color {
+    type Err = ColorParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_hex(s)
+    }
+}
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_hex_rgba())
+    }
+}
+impl From<(u8, u8, u8, u8)> for Color {
+    fn from(val: (u8, u8, u8, u8)) -> Self {
+        Self::rgba(val.0, val.1, val.2, val.3)
+    }
+}
+impl From<(u8, u8, u8)> for Color {
+    fn from(val: (u8, u8, u8)) -> Self {
+        Self::rgb(val.0, val.1, val.2)
+    }
+}
+impl From<[u8; 4]> for Color {
+    fn from(val: [u8; 4]) -> Self {
+        Self::rgba(val[0], val[1], val[2], val[3])
+    }
+}
+impl From<[u8; 3]> for Color {
+    fn from(val: [u8; 3]) -> Self {
+        Self::rgb(val[0], val[1], val[2])
+    }
+}
+impl From<Colorf> for Color {
+    fn from(val: Colorf) -> Self {
+        unsafe { sys::nk_rgba_cf(val.into()) }.into()
+    }
+}
+impl From<sys::nk_color> for Color {
+    fn from(val: sys::nk_color) -> Self {
+        Self::rgba(val.r, val.g, val.b, val.a)
+    }
+}
+impl Into<sys::nk_color> for Color {
+    fn into(self) -> sys::nk_color {
+        sys::nk_color {
+            r: self.r,
+            g: self.g,
+            b: self.b,
+            a: self.a,
+        }
+    }
+}
+
+/// A floating point RGBA color, with channels in the range `0.0..=1.0`.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Colorf {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+impl Colorf {
+    #[inline]
+    pub const fn rgba(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    #[inline]
+    pub const fn rgb(r: f32, g: f32, b: f32) -> Self {
+        Self::rgba(r, g, b, 1.0)
+    }
+
+    pub fn from_hsv(h: f32, s: f32, v: f32) -> Self {
+        Self::from_hsva(h, s, v, 1.0)
+    }
+
+    pub fn from_hsva(h: f32, s: f32, v: f32, a: f32) -> Self {
+        unsafe { sys::nk_hsva_colorf(h, s, v, a) }.into()
+    }
+
+    pub fn to_hsv(self) -> (f32, f32, f32) {
+        let (h, s, v, _) = self.to_hsva();
+        (h, s, v)
+    }
+
+    pub fn to_hsva(self) -> (f32, f32, f32, f32) {
+        let mut hsva = [0.0; 4];
+        unsafe { sys::nk_colorf_hsva_fv(hsva.as_mut_ptr(), self.into()) };
+        (hsva[0], hsva[1], hsva[2], hsva[3])
+    }
+
+    /// Parses a color from `RRGGBB` or `RRGGBBAA` hex digits, optionally prefixed with `#`.
+    pub fn from_hex(hex: &str) -> Result<Self, ColorParseError> {
+        Color::from_hex(hex).map(Into::into)
+    }
+
+    /// The color as `#RRGGBB` hex digits.
+    pub fn to_hex_rgb(self) -> String {
+        Color::from(self).to_hex_rgb()
+    }
+
+    /// The color as `#RRGGBBAA` hex digits.
+    pub fn to_hex_rgba(self) -> String {
+        Color::from(self).to_hex_rgba()
+    }
+}
+impl FromStr for Colorf {
+    type Err = ColorParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_hex(s)
+    }
+}
+impl From<(f32, f32, f32, f32)> for Colorf {
+    fn from(val: (f32, f32, f32, f32)) -> Self {
+        Self::rgba(val.0, val.1, val.2, val.3)
+    }
+}
+impl From<(f32, f32, f32)> for Colorf {
+    fn from(val: (f32, f32, f32)) -> Self {
+        Self::rgb(val.0, val.1, val.2)
+    }
+}
+impl From<[f32; 4]> for Colorf {
+    fn from(val: [f32; 4]) -> Self {
+        Self::rgba(val[0], val[1], val[2], val[3])
+    }
+}
+impl From<[f32; 3]> for Colorf {
+    fn from(val: [f32; 3]) -> Self {
+        Self::rgb(val[0], val[1], val[2])
+    }
+}
+impl From<Color> for Colorf {
+    fn from(val: Color) -> Self {
+        unsafe { sys::nk_color_cf(val.into()) }.into()
+    }
+}
+impl From<sys::nk_colorf> for Colorf {
+    fn from(val: sys::nk_colorf) -> Self {
+        Self::rgba(val.r, val.g, val.b, val.a)
+    }
+}
+impl Into<sys::nk_colorf> for Colorf {
+    fn into(self) -> sys::nk_colorf {
+        sys::nk_colorf {
+            r: self.r,
+            g: self.g,
+            b: self.b,
+            a: self.a,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Color, ColorParseError, Colorf};
+
+    #[test]
+    fn hex() {
+        assert_eq!(Color::from_hex("#FF8000"), Ok(Color::rgb(255, 128, 0)));
+        assert_eq!("ff800040".parse(), Ok(Color::rgba(255, 128, 0, 64)));
+        assert_eq!(Color::rgba(1, 2, 171, 255).to_hex_rgba(), "#0102ABFF");
+        assert_eq!(Color::rgba(1, 2, 171, 255).to_hex_rgb(), "#0102AB");
+
+        assert_eq!(
+            Color::from_hex("#FF80"),
+            Err(ColorParseError::InvalidLength(4))
+        );
+        assert_eq!(
+            Color::from_hex("#FF800G"),
+            Err(ColorParseError::InvalidDigit('G'))
+        );
+    }
+
+    #[test]
+    fn hsv() {
+        let red = Color::rgb(255, 0, 0);
+        assert_eq!(red.to_hsv(), (0, 255, 255));
+        assert_eq!(Color::from_hsv(0, 255, 255), red);
+
+        let (h, s, v, a) = Colorf::rgb(0.0, 1.0, 0.0).to_hsva();
+        assert!((h - 1.0 / 3.0).abs() < 0.001);
+        assert!((s - 1.0).abs() < 0.001 && (v - 1.0).abs() < 0.001 && (a - 1.0).abs() < 0.001);
+        assert_eq!(
+            Color::from(Colorf::from_hsv(h, s, v)),
+            Color::rgb(0, 255, 0)
+        );
+    }
+}
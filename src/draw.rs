@@ -1,5 +1,35 @@
-use crate::{sys, Error, Nuklear};
-use std::ffi::CString;
+use crate::{
+    c_str, math::Rect, sys, window::WindowState, Error, Nuklear, NuklearScope, NuklearType, Phase,
+};
+use std::ffi::{c_void, CStr};
+
+/// The UI phase of a frame, started by [`Nuklear::frame`], in which windows and widgets are
+/// declared.
+pub struct UiScope;
+impl Phase for UiScope {
+    fn leave(context: &Nuklear) {
+        // The frame was abandoned before being drawn
+        context.clear();
+    }
+}
+
+/// The content of a window begun with [`NuklearScope::begin`], in which its widgets and layouts
+/// are declared. The window ends when this scope is left.
+pub struct WindowScope;
+impl Phase for WindowScope {
+    fn leave(context: &Nuklear) {
+        unsafe { sys::nk_end(context.as_ptr()) };
+    }
+}
+
+/// The draw phase of a frame, in which the frame's commands are converted and rendered. The
+/// frame is cleared when this phase ends.
+pub struct DrawScope;
+impl Phase for DrawScope {
+    fn leave(context: &Nuklear) {
+        context.clear();
+    }
+}
 
 const NK_VERTEX_LAYOUT_END: sys::nk_draw_vertex_layout_element =
     sys::nk_draw_vertex_layout_element {
@@ -42,8 +72,13 @@ impl Vertex {
 }
 
 pub struct PrepareDrawHandle(sys::nk_buffer);
+impl Drop for PrepareDrawHandle {
+    fn drop(&mut self) {
+        unsafe { sys::nk_buffer_free(&mut self.0 as _) }
+    }
+}
 
-impl Nuklear {
+impl<'a> NuklearScope<'a, DrawScope> {
     const DEFAULT_BUFFER_INITIAL_SIZE: usize = 4 * 1024;
 
     pub fn prepare_draw(
@@ -59,7 +94,7 @@ impl Nuklear {
         unsafe {
             sys::nk_buffer_init(
                 &mut commands as _,
-                self.allocator.as_ptr(),
+                self.context.allocator.as_ptr(),
                 Self::DEFAULT_BUFFER_INITIAL_SIZE,
             );
 
@@ -75,11 +110,11 @@ impl Nuklear {
             );
 
             sys::nk_convert(
-                self.inner.as_ptr(),
+                self.as_ptr(),
                 &mut commands as _,
                 &mut vertex_buf as _,
                 &mut element_buf as _,
-                &mut self.vertex_config as _,
+                &mut self.context.vertex_config as _,
             );
         }
 
@@ -91,10 +126,10 @@ impl Nuklear {
     where
         F: FnMut(&mut Self, &sys::nk_draw_command),
     {
-        let mut commands = handle.0;
+        let mut handle = handle;
+        let commands = &mut handle.0;
 
-        let mut draw_command =
-            unsafe { sys::nk__draw_begin(self.inner.as_ptr(), &mut commands as _) };
+        let mut draw_command = unsafe { sys::nk__draw_begin(self.as_ptr(), commands as _) };
 
         while !draw_command.is_null() {
             let command = unsafe { &*draw_command };
@@ -103,68 +138,142 @@ impl Nuklear {
                 (each_command)(self, command);
             }
 
-            draw_command = unsafe {
-                sys::nk__draw_next(draw_command, &mut commands as _, self.inner.as_ptr())
-            };
+            draw_command =
+                unsafe { sys::nk__draw_next(draw_command, commands as _, self.as_ptr()) };
         }
 
-        unsafe { sys::nk_clear(self.inner.as_ptr()) };
-
         Ok(())
     }
 }
 
-impl Nuklear {
-    pub fn begin<F, S>(
-        &self,
+impl<'a> NuklearScope<'a, UiScope> {
+    /// Ends the UI phase, moving on to drawing the frame.
+    pub fn finish(self) -> NuklearScope<'a, DrawScope> {
+        self.into_phase()
+    }
+
+    /// Begins a window named and titled `title`, calling `f` to build its content if it is
+    /// visible.
+    pub fn begin<F, S, R>(
+        &mut self,
         title: S,
-        dimensions: (f32, f32, f32, f32),
+        bounds: R,
         flags: PanelFlags,
-        mut f: F,
-    ) where
+        f: F,
+    ) -> Result<WindowState, Error>
+    where
         S: AsRef<str>,
-        F: FnMut(&Self),
+        R: Into<Rect>,
+        F: FnOnce(&NuklearScope<'_, WindowScope>),
     {
-        unsafe {
-            let title_str = CString::new(title.as_ref()).unwrap();
-            if sys::nk_begin(
-                self.inner.as_ptr(),
-                title_str.as_ptr(),
-                sys::nk_rect::from(dimensions),
-                flags.bits,
-            ) != 0
-            {
-                (f)(self)
-            }
-            sys::nk_end(self.inner.as_ptr());
-        }
+        let title = c_str(title.as_ref())?;
+        Ok(self.begin_window(&title, &title, bounds.into(), flags, f))
     }
 
-    pub fn button_label<S>(&self, label: S) -> bool
+    /// Begins a window identified by `name` but displaying `title`, calling `f` to build its
+    /// content if it is visible. This allows changing a window's title, or several windows with
+    /// the same title.
+    pub fn begin_titled<F, N, S, R>(
+        &mut self,
+        name: N,
+        title: S,
+        bounds: R,
+        flags: PanelFlags,
+        f: F,
+    ) -> Result<WindowState, Error>
     where
+        N: AsRef<str>,
         S: AsRef<str>,
+        R: Into<Rect>,
+        F: FnOnce(&NuklearScope<'_, WindowScope>),
     {
-        let label = CString::new(label.as_ref()).unwrap();
-        unsafe { sys::nk_button_label(self.inner.as_ptr(), label.as_ptr()) != 0 }
+        let name = c_str(name.as_ref())?;
+        let title = c_str(title.as_ref())?;
+        Ok(self.begin_window(&name, &title, bounds.into(), flags, f))
     }
 
-    pub fn layout_row_static(&self, height: f32, width: f32, count: usize) {
-        unsafe {
-            sys::nk_layout_row_static(
-                self.inner.as_ptr(),
-                height,
-                width.round() as i32,
-                count as i32,
-            )
+    fn begin_window<F>(
+        &mut self,
+        name: &CStr,
+        title: &CStr,
+        bounds: Rect,
+        flags: PanelFlags,
+        f: F,
+    ) -> WindowState
+    where
+        F: FnOnce(&NuklearScope<'_, WindowScope>),
+    {
+        let visible = unsafe {
+            sys::nk_begin_titled(
+                self.as_ptr(),
+                name.as_ptr(),
+                title.as_ptr(),
+                bounds.into(),
+                flags.bits,
+            ) != 0
+        };
+        let state = self.current_window_state();
+
+        // Borrowing the frame for the window's content keeps windows from being begun inside of
+        // each other, and `nk_end` is called even for hidden windows when the scope is dropped.
+        let window = NuklearScope::<WindowScope>::new(self.context);
+        if visible {
+            (f)(&window)
         }
+
+        state
+    }
+}
+
+/// An image drawn from a texture of the renderer, identified the same way as the font atlas
+/// texture.
+#[derive(Copy, Clone)]
+pub struct Image(sys::nk_image);
+impl Image {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    pub fn from_id(id: usize) -> Self {
+        Self(unsafe { sys::nk_image_id(id as i32) })
     }
 
-    pub fn layout_row_dynamic(&self, height: f32, cols: usize) {
-        unsafe { sys::nk_layout_row_dynamic(self.inner.as_ptr(), height, cols as i32) }
+    pub fn from_ptr(ptr: *mut c_void) -> Self {
+        Self(unsafe { sys::nk_image_ptr(ptr) })
     }
 
-    pub fn layout_row_begin(&self, format: sys::nk_layout_format, height: f32, cols: usize) {
-        unsafe { sys::nk_layout_row_begin(self.inner.as_ptr(), format, height, cols as i32) }
+    /// The `region` of a texture of `size` pixels.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    pub fn sub_image_id<R>(id: usize, size: (u16, u16), region: R) -> Self
+    where
+        R: Into<Rect>,
+    {
+        Self(unsafe { sys::nk_subimage_id(id as i32, size.0, size.1, region.into().into()) })
+    }
+}
+impl Into<sys::nk_image> for Image {
+    fn into(self) -> sys::nk_image {
+        self.0
+    }
+}
+
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Symbol {
+    None = sys::nk_symbol_type_NK_SYMBOL_NONE,
+    X = sys::nk_symbol_type_NK_SYMBOL_X,
+    Underscore = sys::nk_symbol_type_NK_SYMBOL_UNDERSCORE,
+    CircleSolid = sys::nk_symbol_type_NK_SYMBOL_CIRCLE_SOLID,
+    CircleOutline = sys::nk_symbol_type_NK_SYMBOL_CIRCLE_OUTLINE,
+    RectSolid = sys::nk_symbol_type_NK_SYMBOL_RECT_SOLID,
+    RectOutline = sys::nk_symbol_type_NK_SYMBOL_RECT_OUTLINE,
+    TriangleUp = sys::nk_symbol_type_NK_SYMBOL_TRIANGLE_UP,
+    TriangleDown = sys::nk_symbol_type_NK_SYMBOL_TRIANGLE_DOWN,
+    TriangleLeft = sys::nk_symbol_type_NK_SYMBOL_TRIANGLE_LEFT,
+    TriangleRight = sys::nk_symbol_type_NK_SYMBOL_TRIANGLE_RIGHT,
+    Plus = sys::nk_symbol_type_NK_SYMBOL_PLUS,
+    Minus = sys::nk_symbol_type_NK_SYMBOL_MINUS,
+}
+impl Into<sys::nk_symbol_type> for Symbol {
+    fn into(self) -> sys::nk_symbol_type {
+        self as sys::nk_symbol_type
     }
 }
 
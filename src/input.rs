@@ -1,6 +1,7 @@
-use crate::{sys, Nuklear, NuklearScope};
+use crate::{math::Vec2, sys, Nuklear, NuklearScope, NuklearType, Phase};
 
 #[repr(u32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Button {
     Left = 0,
     Middle = 1,
@@ -8,38 +9,230 @@ pub enum Button {
     Double = 3,
 }
 
-pub trait InputScope {}
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Key {
+    Shift = sys::nk_keys_NK_KEY_SHIFT,
+    Ctrl = sys::nk_keys_NK_KEY_CTRL,
+    Delete = sys::nk_keys_NK_KEY_DEL,
+    Enter = sys::nk_keys_NK_KEY_ENTER,
+    Tab = sys::nk_keys_NK_KEY_TAB,
+    Backspace = sys::nk_keys_NK_KEY_BACKSPACE,
+    Copy = sys::nk_keys_NK_KEY_COPY,
+    Cut = sys::nk_keys_NK_KEY_CUT,
+    Paste = sys::nk_keys_NK_KEY_PASTE,
+    Up = sys::nk_keys_NK_KEY_UP,
+    Down = sys::nk_keys_NK_KEY_DOWN,
+    Left = sys::nk_keys_NK_KEY_LEFT,
+    Right = sys::nk_keys_NK_KEY_RIGHT,
+    TextInsertMode = sys::nk_keys_NK_KEY_TEXT_INSERT_MODE,
+    TextReplaceMode = sys::nk_keys_NK_KEY_TEXT_REPLACE_MODE,
+    TextResetMode = sys::nk_keys_NK_KEY_TEXT_RESET_MODE,
+    TextLineStart = sys::nk_keys_NK_KEY_TEXT_LINE_START,
+    TextLineEnd = sys::nk_keys_NK_KEY_TEXT_LINE_END,
+    TextStart = sys::nk_keys_NK_KEY_TEXT_START,
+    TextEnd = sys::nk_keys_NK_KEY_TEXT_END,
+    TextUndo = sys::nk_keys_NK_KEY_TEXT_UNDO,
+    TextRedo = sys::nk_keys_NK_KEY_TEXT_REDO,
+    TextSelectAll = sys::nk_keys_NK_KEY_TEXT_SELECT_ALL,
+    TextWordLeft = sys::nk_keys_NK_KEY_TEXT_WORD_LEFT,
+    TextWordRight = sys::nk_keys_NK_KEY_TEXT_WORD_RIGHT,
+    ScrollStart = sys::nk_keys_NK_KEY_SCROLL_START,
+    ScrollEnd = sys::nk_keys_NK_KEY_SCROLL_END,
+    ScrollDown = sys::nk_keys_NK_KEY_SCROLL_DOWN,
+    ScrollUp = sys::nk_keys_NK_KEY_SCROLL_UP,
+}
+impl Into<sys::nk_keys> for Key {
+    fn into(self) -> sys::nk_keys {
+        self as sys::nk_keys
+    }
+}
+
+/// The input phase of a frame, started by [`Nuklear::begin_input`].
 pub struct Scope;
-impl InputScope for Scope {}
+impl Phase for Scope {
+    fn leave(context: &Nuklear) {
+        unsafe {
+            sys::nk_input_end(context.as_ptr());
+        }
+    }
+}
 
 impl Nuklear {
-    pub fn begin_input(&mut self) {
+    /// Starts feeding input for the next frame. The input phase ends when the returned scope is
+    /// dropped.
+    pub fn begin_input(&mut self) -> NuklearScope<'_, Scope> {
         unsafe {
-            sys::nk_input_begin(self.inner.as_ptr());
+            sys::nk_input_begin(self.as_ptr());
         }
+
+        NuklearScope::new(self)
     }
 
-    pub fn end_input(&mut self) {
-        unsafe {
-            sys::nk_input_end(self.inner.as_ptr());
+    /// Whether the UI of the last frame wants mouse input, i.e. the mouse is over one of its
+    /// windows or a widget is being interacted with. Input the UI wants should not also be acted on
+    /// by the application.
+    pub fn wants_mouse(&self) -> bool {
+        // `nk_item_is_any_active` would always be false here, as the frame was already cleared
+        self.item_active.get() || unsafe { sys::nk_window_is_any_hovered(self.as_ptr()) != 0 }
+    }
+
+    /// Whether the UI of the last frame wants keyboard input, i.e. a text field or property of the
+    /// focused window is being edited.
+    pub fn wants_keyboard(&self) -> bool {
+        let inner = self.inner.borrow();
+        if inner.active.is_null() {
+            return false;
         }
+
+        let active = unsafe { &*inner.active };
+        active.edit.active != 0 || active.property.active != 0
     }
 }
-/*
-impl<T> NuklearScope<T>
-where
-    T: InputScope,
-{*/
-impl Nuklear {
+
+impl<'a> NuklearScope<'a, Scope> {
     pub fn input_motion(&mut self, x: i32, y: i32) {
         unsafe {
-            sys::nk_input_motion(self.inner.as_ptr(), x, y);
+            sys::nk_input_motion(self.as_ptr(), x, y);
         }
     }
 
     pub fn input_button(&mut self, x: i32, y: i32, button: Button, state: bool) {
         unsafe {
-            sys::nk_input_button(self.inner.as_ptr(), button as u32, x, y, state.into());
+            sys::nk_input_button(self.as_ptr(), button as u32, x, y, state.into());
+        }
+    }
+
+    /// Inputs a scroll by `delta` lines, with positive values scrolling up/left.
+    pub fn input_scroll<V>(&mut self, delta: V)
+    where
+        V: Into<Vec2>,
+    {
+        unsafe {
+            sys::nk_input_scroll(self.as_ptr(), delta.into().into());
+        }
+    }
+
+    pub fn input_key(&mut self, key: Key, down: bool) {
+        unsafe {
+            sys::nk_input_key(self.as_ptr(), key.into(), down.into());
         }
     }
+
+    /// Inputs a single byte as a character. Bytes outside of ASCII are treated as Latin-1.
+    pub fn input_char(&mut self, c: u8) {
+        if c.is_ascii() {
+            unsafe {
+                #[allow(clippy::cast_possible_wrap)]
+                sys::nk_input_char(self.as_ptr(), c as std::os::raw::c_char);
+            }
+        } else {
+            self.input_unicode(char::from(c));
+        }
+    }
+
+    pub fn input_unicode(&mut self, c: char) {
+        unsafe {
+            sys::nk_input_unicode(self.as_ptr(), c as sys::nk_rune);
+        }
+    }
+
+    /// Inputs every character of `text`. Nuklear only buffers `NK_INPUT_MAX` bytes of text per
+    /// frame, anything beyond that is dropped.
+    pub fn input_text<S>(&mut self, text: S)
+    where
+        S: AsRef<str>,
+    {
+        for c in text.as_ref().chars() {
+            let mut glyph = [0_u8; sys::NK_UTF_SIZE as usize];
+            c.encode_utf8(&mut glyph);
+
+            unsafe {
+                sys::nk_input_glyph(self.as_ptr(), glyph.as_mut_ptr().cast());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Button;
+    use crate::{alloc, draw::PanelFlags, tests::test_context, Nuklear};
+
+    fn input_text_of(f: impl FnOnce(&mut crate::NuklearScope<super::Scope>)) -> String {
+        let mut context = test_context(alloc::global::create());
+
+        (f)(&mut context.begin_input());
+
+        let inner = context.inner.borrow();
+        let keyboard = &inner.input.keyboard;
+        #[allow(clippy::cast_sign_loss)]
+        let bytes = keyboard.text[..keyboard.text_len as usize]
+            .iter()
+            .map(|c| *c as u8)
+            .collect::<Vec<_>>();
+
+        String::from_utf8(bytes).unwrap()
+    }
+
+    #[test]
+    fn input_text() {
+        assert_eq!(input_text_of(|input| input.input_text("Grüße")), "Grüße");
+        assert_eq!(input_text_of(|input| input.input_text("日本")), "日本");
+    }
+
+    #[test]
+    fn input_char_and_unicode() {
+        assert_eq!(
+            input_text_of(|input| {
+                input.input_char(b'a');
+                input.input_char(0xE4);
+                input.input_unicode('語');
+            }),
+            "aä語"
+        );
+    }
+
+    fn slider_frame(context: &mut Nuklear, value: &mut f32) {
+        context
+            .frame()
+            .begin(
+                "Test",
+                (0.0, 0.0, 200.0, 200.0),
+                PanelFlags::BORDER,
+                |ctx| {
+                    ctx.layout_row_dynamic(30.0, 1);
+                    ctx.slider(value, 0.0..=100.0, 1.0);
+                },
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn wants_mouse_while_dragging_outside() {
+        let mut context = test_context(alloc::global::create());
+        let mut value = 50.0;
+
+        drop(context.begin_input());
+        slider_frame(&mut context, &mut value);
+
+        // Grab the slider's cursor in the middle of the first row, and drag it out of the window
+        {
+            let mut input = context.begin_input();
+            input.input_motion(100, 19);
+            input.input_button(100, 19, Button::Left, true);
+        }
+        slider_frame(&mut context, &mut value);
+
+        context.begin_input().input_motion(400, 19);
+        slider_frame(&mut context, &mut value);
+        assert!(!context.window("Test").unwrap().is_hovered());
+        assert!(context.wants_mouse());
+
+        context
+            .begin_input()
+            .input_button(400, 19, Button::Left, false);
+        slider_frame(&mut context, &mut value);
+        assert!(!context.wants_mouse());
+    }
 }
@@ -0,0 +1,365 @@
+use crate::{
+    draw::WindowScope,
+    math::{Rect, Vec2},
+    sys, NuklearScope, NuklearType,
+};
+use std::cell::Cell;
+
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum LayoutFormat {
+    /// Widths are ratios of the available row width
+    Dynamic = sys::nk_layout_format_NK_DYNAMIC,
+    /// Widths are in pixels
+    Static = sys::nk_layout_format_NK_STATIC,
+}
+impl Into<sys::nk_layout_format> for LayoutFormat {
+    fn into(self) -> sys::nk_layout_format {
+        self as sys::nk_layout_format
+    }
+}
+
+/// A row begun with [`NuklearScope::layout_row_begin`], in which the width of every widget is
+/// pushed before adding it. Other layouts can not be started while the row is open, and are
+/// ignored.
+pub struct LayoutRow<'s, 'a> {
+    ui: &'s NuklearScope<'a, WindowScope>,
+}
+impl<'s, 'a> LayoutRow<'s, 'a> {
+    /// Sets the width of the next widget, as a ratio or in pixels depending on the row's format.
+    pub fn push(&self, width: f32) {
+        unsafe { sys::nk_layout_row_push(self.ui.as_ptr(), width) }
+    }
+}
+impl<'s, 'a> std::ops::Deref for LayoutRow<'s, 'a> {
+    type Target = NuklearScope<'a, WindowScope>;
+
+    fn deref(&self) -> &Self::Target {
+        self.ui
+    }
+}
+
+/// The columns of a row begun with [`NuklearScope::layout_row_template`]. A template holds at most
+/// `NK_MAX_LAYOUT_ROW_TEMPLATE_COLUMNS` columns, any columns added past that are ignored.
+pub struct LayoutTemplate<'s, 'a> {
+    ui: &'s NuklearScope<'a, WindowScope>,
+    columns: Cell<u32>,
+}
+impl<'s, 'a> LayoutTemplate<'s, 'a> {
+    // Nuklear asserts on adding a column to a full template
+    fn push(&self, f: impl FnOnce()) {
+        if !self.is_full() {
+            self.columns.set(self.columns.get() + 1);
+            (f)();
+        }
+    }
+
+    /// Adds a column sharing the remaining width with the other dynamic columns.
+    pub fn dynamic(&self) {
+        self.push(|| unsafe { sys::nk_layout_row_template_push_dynamic(self.ui.as_ptr()) })
+    }
+
+    /// Adds a column sharing the remaining width, but never narrower than `min_width`.
+    pub fn variable(&self, min_width: f32) {
+        self.push(|| unsafe {
+            sys::nk_layout_row_template_push_variable(self.ui.as_ptr(), min_width)
+        })
+    }
+
+    /// Adds a column of exactly `width` pixels.
+    pub fn fixed(&self, width: f32) {
+        self.push(|| unsafe { sys::nk_layout_row_template_push_static(self.ui.as_ptr(), width) })
+    }
+
+    /// The number of columns added so far.
+    pub fn len(&self) -> usize {
+        self.columns.get() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.columns.get() == 0
+    }
+
+    /// Whether no more columns can be added.
+    pub fn is_full(&self) -> bool {
+        self.columns.get() >= sys::NK_MAX_LAYOUT_ROW_TEMPLATE_COLUMNS
+    }
+}
+
+/// A free-form area begun with [`NuklearScope::layout_space`], in which every widget is placed at
+/// the bounds pushed before adding it. Local coordinates are relative to the top left of the area.
+/// Other layouts can not be started while the space is open, and are ignored.
+pub struct LayoutSpace<'s, 'a> {
+    ui: &'s NuklearScope<'a, WindowScope>,
+}
+impl<'s, 'a> LayoutSpace<'s, 'a> {
+    /// Sets the bounds of the next widget, in local coordinates for a static space and as ratios
+    /// of the space for a dynamic one.
+    pub fn push<R>(&self, bounds: R)
+    where
+        R: Into<Rect>,
+    {
+        unsafe { sys::nk_layout_space_push(self.ui.as_ptr(), bounds.into().into()) }
+    }
+
+    /// The bounds of the whole space, in screen coordinates.
+    pub fn bounds(&self) -> Rect {
+        unsafe { sys::nk_layout_space_bounds(self.ui.as_ptr()) }.into()
+    }
+
+    pub fn to_screen<V>(&self, position: V) -> Vec2
+    where
+        V: Into<Vec2>,
+    {
+        unsafe { sys::nk_layout_space_to_screen(self.ui.as_ptr(), position.into().into()) }.into()
+    }
+
+    pub fn to_local<V>(&self, position: V) -> Vec2
+    where
+        V: Into<Vec2>,
+    {
+        unsafe { sys::nk_layout_space_to_local(self.ui.as_ptr(), position.into().into()) }.into()
+    }
+
+    pub fn rect_to_screen<R>(&self, rect: R) -> Rect
+    where
+        R: Into<Rect>,
+    {
+        unsafe { sys::nk_layout_space_rect_to_screen(self.ui.as_ptr(), rect.into().into()) }.into()
+    }
+
+    pub fn rect_to_local<R>(&self, rect: R) -> Rect
+    where
+        R: Into<Rect>,
+    {
+        unsafe { sys::nk_layout_space_rect_to_local(self.ui.as_ptr(), rect.into().into()) }.into()
+    }
+}
+impl<'s, 'a> std::ops::Deref for LayoutSpace<'s, 'a> {
+    type Target = NuklearScope<'a, WindowScope>;
+
+    fn deref(&self) -> &Self::Target {
+        self.ui
+    }
+}
+
+impl<'a> NuklearScope<'a, WindowScope> {
+    // Calls `f` with a layout marked as open, which ignores any other layout started until it ends
+    fn with_open_layout(&self, f: impl FnOnce()) {
+        self.context.layout_open.set(true);
+        (f)();
+        self.context.layout_open.set(false);
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    pub fn layout_row_static(&self, height: f32, width: f32, count: usize) {
+        if self.context.layout_open.get() {
+            return;
+        }
+
+        unsafe {
+            sys::nk_layout_row_static(self.as_ptr(), height, width.round() as i32, count as i32)
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    pub fn layout_row_dynamic(&self, height: f32, cols: usize) {
+        if self.context.layout_open.get() {
+            return;
+        }
+
+        unsafe { sys::nk_layout_row_dynamic(self.as_ptr(), height, cols as i32) }
+    }
+
+    /// Begins a row of `cols` widgets, calling `f` to push the width of and add each of them. This
+    /// is ignored, without calling `f`, inside of another row or space begun with a closure.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    pub fn layout_row_begin<F>(&self, format: LayoutFormat, height: f32, cols: usize, f: F)
+    where
+        F: FnOnce(&LayoutRow<'_, 'a>),
+    {
+        if self.context.layout_open.get() {
+            return;
+        }
+
+        unsafe { sys::nk_layout_row_begin(self.as_ptr(), format.into(), height, cols as i32) };
+
+        self.with_open_layout(|| (f)(&LayoutRow { ui: self }));
+
+        unsafe { sys::nk_layout_row_end(self.as_ptr()) };
+    }
+
+    /// Begins a row with a widget for each entry of `widths`, as ratios or in pixels depending on
+    /// `format`.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    pub fn layout_row(&self, format: LayoutFormat, height: f32, widths: &[f32]) {
+        if self.context.layout_open.get() {
+            return;
+        }
+
+        // Nuklear keeps using the widths for as long as the row is in use, which may be after
+        // more rows were nested inside of it, so they are kept until the end of the frame.
+        let ptr = {
+            let mut layout_widths = self.context.layout_widths.borrow_mut();
+            layout_widths.push(widths.into());
+            layout_widths.last().unwrap().as_ptr()
+        };
+
+        unsafe {
+            sys::nk_layout_row(
+                self.as_ptr(),
+                format.into(),
+                height,
+                widths.len() as i32,
+                ptr,
+            )
+        }
+    }
+
+    /// Sets the minimum height of the rows that follow, instead of the font height.
+    pub fn layout_set_min_row_height(&self, height: f32) {
+        unsafe { sys::nk_layout_set_min_row_height(self.as_ptr(), height) }
+    }
+
+    pub fn layout_reset_min_row_height(&self) {
+        unsafe { sys::nk_layout_reset_min_row_height(self.as_ptr()) }
+    }
+
+    /// Begins a row laid out from the columns `f` adds to the template, after which a widget is
+    /// added for each column. Like the other layouts begun with a closure, this is ignored inside
+    /// of an open row or space, and ignores the layouts started inside of `f`.
+    pub fn layout_row_template<F>(&self, height: f32, f: F)
+    where
+        F: FnOnce(&LayoutTemplate<'_, 'a>),
+    {
+        if self.context.layout_open.get() {
+            return;
+        }
+
+        unsafe { sys::nk_layout_row_template_begin(self.as_ptr(), height) };
+
+        self.with_open_layout(|| {
+            (f)(&LayoutTemplate {
+                ui: self,
+                columns: Cell::new(0),
+            })
+        });
+
+        unsafe { sys::nk_layout_row_template_end(self.as_ptr()) };
+    }
+
+    /// Begins a free-form area of `height` holding at most `widget_count` widgets, calling `f` to
+    /// place and add them. This is ignored, without calling `f`, inside of another row or space
+    /// begun with a closure.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    pub fn layout_space<F>(&self, format: LayoutFormat, height: f32, widget_count: usize, f: F)
+    where
+        F: FnOnce(&LayoutSpace<'_, 'a>),
+    {
+        if self.context.layout_open.get() {
+            return;
+        }
+
+        unsafe {
+            sys::nk_layout_space_begin(self.as_ptr(), format.into(), height, widget_count as i32)
+        };
+
+        self.with_open_layout(|| (f)(&LayoutSpace { ui: self }));
+
+        unsafe { sys::nk_layout_space_end(self.as_ptr()) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LayoutFormat;
+    use crate::{alloc, draw::PanelFlags, sys, tests::test_context};
+
+    #[test]
+    fn template_column_limit() {
+        let mut context = test_context(alloc::global::create());
+
+        context
+            .frame()
+            .begin(
+                "Test",
+                (0.0, 0.0, 200.0, 200.0),
+                PanelFlags::BORDER,
+                |ctx| {
+                    ctx.layout_row_template(30.0, |template| {
+                        for _ in 0..sys::NK_MAX_LAYOUT_ROW_TEMPLATE_COLUMNS {
+                            assert!(!template.is_full());
+                            template.dynamic();
+                        }
+                        assert!(template.is_full());
+
+                        template.dynamic();
+                        template.fixed(10.0);
+                        assert_eq!(
+                            template.len(),
+                            sys::NK_MAX_LAYOUT_ROW_TEMPLATE_COLUMNS as usize
+                        );
+                    });
+                },
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn layout_inside_row() {
+        let mut context = test_context(alloc::global::create());
+
+        context
+            .frame()
+            .begin(
+                "Test",
+                (0.0, 0.0, 200.0, 200.0),
+                PanelFlags::BORDER,
+                |ctx| {
+                    ctx.layout_row_begin(LayoutFormat::Dynamic, 30.0, 2, |row| {
+                        row.push(0.5);
+                        row.layout_row_dynamic(30.0, 1);
+                        row.layout_row(LayoutFormat::Static, 30.0, &[10.0, 20.0]);
+                        row.layout_row_begin(LayoutFormat::Static, 30.0, 1, |_| unreachable!());
+                        row.button_label("A");
+
+                        row.push(0.5);
+                        row.button_label("B");
+                    });
+
+                    // Layouts work again once the row ended
+                    ctx.layout_row_dynamic(30.0, 1);
+                    ctx.button_label("C");
+                },
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn layout_inside_space() {
+        let mut context = test_context(alloc::global::create());
+
+        context
+            .frame()
+            .begin(
+                "Test",
+                (0.0, 0.0, 200.0, 200.0),
+                PanelFlags::BORDER,
+                |ctx| {
+                    ctx.layout_space(LayoutFormat::Static, 100.0, 2, |space| {
+                        space.push((0.0, 0.0, 50.0, 30.0));
+                        space.layout_row_dynamic(30.0, 1);
+                        space.layout_space(LayoutFormat::Static, 30.0, 1, |_| unreachable!());
+                        space.button_label("A");
+
+                        space.push((60.0, 0.0, 50.0, 30.0));
+                        space.button_label("B");
+                    });
+
+                    ctx.layout_row_dynamic(30.0, 1);
+                    ctx.button_label("C");
+                },
+            )
+            .unwrap();
+    }
+}
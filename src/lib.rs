@@ -3,18 +3,39 @@
     dead_code,
     unused_variables,
     clippy::must_use_candidate,
-    clippy::missing_errors_doc
+    clippy::missing_errors_doc,
+    clippy::module_name_repetitions
 )]
 #![cfg_attr(feature = "std", feature(allocator_api))]
 
 pub mod alloc;
+pub mod button;
+pub mod check;
+pub mod color;
 pub mod draw;
 pub mod font;
 pub mod input;
+pub mod layout;
+pub mod math;
+pub mod property;
+pub mod select;
+pub mod slider;
+pub mod style;
+pub mod text;
+pub mod window;
 
+pub use color::{Color, Colorf};
 pub use nukly_sys as sys;
 
-use std::{cell::RefCell, pin::Pin, sync::Arc};
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashSet,
+    ffi::{CString, NulError},
+    marker::PhantomData,
+    os::raw::{c_char, c_int},
+    pin::Pin,
+    sync::Arc,
+};
 
 pub trait NuklearType<T> {
     fn as_ptr(&self) -> *mut T;
@@ -24,20 +45,68 @@ pub trait NuklearType<T> {
 pub enum Error {
     #[error("An Unknown error occurred")]
     Unknown,
+    #[error("The font atlas does not contain a font at index {0}")]
+    InvalidFont(usize),
+    #[error("Nuklear requires a NUL-terminated string here, but the string contains a NUL byte")]
+    InteriorNul(#[from] NulError),
+}
+
+// Only for the few Nuklear calls without a length-based variant, like window names
+pub(crate) fn c_str(s: &str) -> Result<CString, Error> {
+    Ok(CString::new(s)?)
+}
+
+// Passes a string to one of Nuklear's length-based `*_text` calls, which need no allocation and
+// have no issue with NUL bytes.
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+pub(crate) fn text_ptr(s: &str) -> (*const c_char, c_int) {
+    (
+        s.as_ptr().cast(),
+        s.len().min(c_int::max_value() as usize) as c_int,
+    )
 }
 
 pub struct Nuklear {
-    inner: RefCell<sys::nk_context>,
+    // Nuklear keeps pointers into the context (e.g. every window's command buffer points at
+    // `nk_context::memory`), so it must never move once `nk_init` has been called.
+    inner: Pin<Box<RefCell<sys::nk_context>>>,
     allocator: Pin<Arc<dyn alloc::Allocator>>,
     vertex_config: sys::nk_convert_config,
+    // The context holds a raw pointer to one of the atlas fonts, so keep the atlas alive for as
+    // long as the context is.
+    font_image: Arc<font::Image>,
+    // Widths passed to `nk_layout_row`, which Nuklear points to until the end of the frame
+    layout_widths: RefCell<Vec<Box<[f32]>>>,
+    // Whether a layout that widgets are pushed into is open, in which starting another layout
+    // makes Nuklear assert on the next push
+    layout_open: Cell<bool>,
+    // Name hashes of the windows hidden by their close button, as Nuklear does not tell those
+    // apart from windows hidden by `nk_window_show`
+    closed_windows: RefCell<HashSet<sys::nk_hash>>,
+    // Reused to NUL-terminate property names without allocating for every property
+    property_name: RefCell<Vec<u8>>,
+    // The cursor the last frame wanted, as `nk_clear` resets it before it can be queried
+    cursor: Cell<style::Cursor>,
+    // Whether a widget of the last frame was being interacted with, which `nk_clear` also resets
+    item_active: Cell<bool>,
 }
 
 impl Nuklear {
+    /// Creates a new context drawing with the font at index `font` of the atlas owned by
+    /// `font_image`. The context keeps a reference to the image, so it can never outlive its font.
     pub fn create(
         allocator: Pin<Arc<dyn alloc::Allocator>>,
-        font: &font::Font,
+        font_image: Arc<font::Image>,
+        font: usize,
     ) -> Result<Self, Error> {
-        let inner = RefCell::new(sys::nk_context::default());
+        let font_handle = font_image
+            .atlas()
+            .fonts()
+            .get(font)
+            .ok_or(Error::InvalidFont(font))?
+            .handle();
+
+        let inner = Box::pin(RefCell::new(sys::nk_context::default()));
 
         let mut vertex_config = sys::nk_convert_config::default();
         draw::Vertex::apply(&mut vertex_config);
@@ -49,43 +118,248 @@ impl Nuklear {
         vertex_config.shape_AA = sys::nk_anti_aliasing_NK_ANTI_ALIASING_ON;
 
         unsafe {
-            sys::nk_init(inner.as_ptr(), allocator.as_ptr(), font.handle());
+            sys::nk_init(inner.as_ptr(), allocator.as_ptr(), font_handle);
         }
 
-        Ok(Self {
+        let mut this = Self {
             vertex_config,
             allocator,
             inner,
-        })
+            font_image,
+            layout_widths: RefCell::default(),
+            layout_open: Cell::new(false),
+            closed_windows: RefCell::default(),
+            property_name: RefCell::default(),
+            cursor: Cell::new(style::Cursor::Arrow),
+            item_active: Cell::new(false),
+        };
+        this.load_cursors();
+
+        Ok(this)
+    }
+
+    #[inline]
+    pub fn font_image(&self) -> &Arc<font::Image> {
+        &self.font_image
+    }
+
+    /// Starts building the UI for this frame. Input for the frame must have been fed through
+    /// [`Nuklear::begin_input`] beforehand.
+    pub fn frame(&mut self) -> NuklearScope<'_, draw::UiScope> {
+        self.layout_widths.borrow_mut().clear();
+        self.layout_open.set(false);
+
+        NuklearScope::new(self)
+    }
+
+    // Ends the frame, first keeping what it reported for the queries made until the next frame
+    pub(crate) fn clear(&self) {
+        self.cursor.set(self.active_cursor());
+        self.item_active.set(
+            self.inner.borrow().last_widget_state & sys::nk_widget_states_NK_WIDGET_STATE_MODIFIED
+                != 0,
+        );
+
+        unsafe { sys::nk_clear(self.as_ptr()) };
+    }
+}
+impl NuklearType<sys::nk_context> for Nuklear {
+    fn as_ptr(&self) -> *mut sys::nk_context {
+        self.inner.as_ptr()
     }
 }
-/*
 impl Drop for Nuklear {
-    pub fn drop(self) {
-        // TODO: why is this overwriting memory?
-        //unsafe { sys::nk_free(self.inner.as_ptr()) }
+    fn drop(&mut self) {
+        // The allocator is still alive here, as fields are only dropped after this returns.
+        unsafe { sys::nk_free(self.inner.as_ptr()) }
     }
-}*/
+}
+
+/// A phase of a Nuklear frame, which is left when its [`NuklearScope`] is dropped.
+pub trait Phase {
+    fn leave(context: &Nuklear);
+}
+
+/// Exclusive access to a [`Nuklear`] context for the duration of a single phase of a frame
+/// (input, UI or drawing). Each phase only exposes the calls valid in it.
+pub struct NuklearScope<'a, T: Phase> {
+    context: &'a mut Nuklear,
+    _marker: PhantomData<T>,
+}
+impl<'a, T: Phase> NuklearScope<'a, T> {
+    pub(crate) fn new(context: &'a mut Nuklear) -> Self {
+        Self {
+            context,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Moves into the next phase without leaving the current one.
+    pub(crate) fn into_phase<U: Phase>(self) -> NuklearScope<'a, U> {
+        let this = std::mem::ManuallyDrop::new(self);
+        // This is safe as `this` is never dropped, so the reference is not used twice
+        NuklearScope::new(unsafe { std::ptr::read(&this.context) })
+    }
+}
+impl<'a, T: Phase> NuklearType<sys::nk_context> for NuklearScope<'a, T> {
+    fn as_ptr(&self) -> *mut sys::nk_context {
+        self.context.as_ptr()
+    }
+}
+// Queries on the context are valid in every phase, only calls building the frame are phase specific
+impl<'a, T: Phase> std::ops::Deref for NuklearScope<'a, T> {
+    type Target = Nuklear;
 
-pub struct NuklearScope<T> {
-    inner: Nuklear,
-    _marker: std::marker::PhantomData<T>,
+    fn deref(&self) -> &Nuklear {
+        self.context
+    }
+}
+impl<'a, T: Phase> Drop for NuklearScope<'a, T> {
+    fn drop(&mut self) {
+        T::leave(self.context);
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::{alloc, font, Nuklear};
+    use std::{pin::Pin, sync::Arc};
+
+    /// An image of the default font, without a texture.
+    pub(crate) fn test_image(allocator: Pin<Arc<dyn alloc::Allocator>>) -> font::Image {
+        font::Atlas::new(allocator)
+            .with_default()
+            .bake(font::AtlasFormat::Rgba32)
+            .unwrap()
+            .build(|_, _| 1)
+    }
+
+    /// A context drawing with the default font.
+    pub(crate) fn test_context(allocator: Pin<Arc<dyn alloc::Allocator>>) -> Nuklear {
+        Nuklear::create(allocator.clone(), Arc::new(test_image(allocator)), 0).unwrap()
+    }
 
     #[test]
     fn full_test() {
+        let context = test_context(alloc::global::create());
+    }
+
+    #[test]
+    fn invalid_font() {
         let allocator = alloc::global::create();
+        let image = test_image(allocator.clone());
 
-        let atlas = font::Atlas::new(allocator.clone());
-        let image = atlas
-            .with_default()
-            .bake(font::AtlasFormat::Rgba32)
-            .unwrap()
-            .build(|_, _| 1);
-        let context = Nuklear::create(allocator, &image.atlas().fonts()[0]).unwrap();
+        assert!(matches!(
+            Nuklear::create(allocator, Arc::new(image), 1),
+            Err(super::Error::InvalidFont(1))
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc-counters")]
+    fn drop_frees_all_memory() {
+        use std::sync::atomic::Ordering;
+
+        let allocator = alloc::global::create();
+
+        {
+            // Move the context around after a frame, to make sure nothing points into the old location.
+            let mut context = {
+                let mut context = test_context(allocator.clone());
+
+                let mut input = context.begin_input();
+                input.input_motion(10, 10);
+                drop(input);
+
+                context
+                    .frame()
+                    .begin(
+                        "Test",
+                        (0.0, 0.0, 100.0, 100.0),
+                        crate::draw::PanelFlags::BORDER,
+                        |ctx| {
+                            ctx.layout_row_dynamic(30.0, 1);
+                            ctx.button_label("Test");
+                        },
+                    )
+                    .unwrap();
+
+                context
+            };
+
+            let mut ui = context.frame();
+            ui.begin(
+                "Test",
+                (0.0, 0.0, 100.0, 100.0),
+                crate::draw::PanelFlags::BORDER,
+                |ctx| {
+                    ctx.layout_row_dynamic(30.0, 1);
+                    ctx.button_label("Test");
+                },
+            )
+            .unwrap();
+
+            let mut vertices = vec![0_u8; 64 * 1024];
+            let mut elements = vec![0_u8; 16 * 1024];
+            let mut draw = ui.finish();
+            let handle = draw.prepare_draw(&mut vertices, &mut elements).unwrap();
+            draw.draw(handle, |_, _| {}).unwrap();
+
+            assert!(
+                allocator
+                    .counters()
+                    .current_allocated_bytes
+                    .load(Ordering::Relaxed)
+                    > 0
+            );
+        }
+
+        assert_eq!(
+            allocator
+                .counters()
+                .current_allocated_bytes
+                .load(Ordering::Relaxed),
+            0
+        );
+        assert_eq!(
+            allocator.counters().alloc_count.load(Ordering::Relaxed),
+            allocator.counters().free_count.load(Ordering::Relaxed)
+        );
+    }
+
+    #[test]
+    fn interior_nul() {
+        let mut context = test_context(alloc::global::create());
+
+        let mut ui = context.frame();
+        assert!(matches!(
+            ui.begin(
+                "Test\0",
+                (0.0, 0.0, 100.0, 100.0),
+                crate::draw::PanelFlags::BORDER,
+                |_| {}
+            ),
+            Err(super::Error::InteriorNul(_))
+        ));
+        assert!(matches!(
+            ui.window("Test\0"),
+            Err(super::Error::InteriorNul(_))
+        ));
+
+        // Widgets taking a length never need a NUL-terminated string
+        ui.begin(
+            "Test",
+            (0.0, 0.0, 100.0, 100.0),
+            crate::draw::PanelFlags::BORDER,
+            |ctx| {
+                ctx.layout_row_dynamic(30.0, 1);
+                ctx.button_label("Te\0st");
+                assert!(matches!(
+                    ctx.property("Te\0st", &mut 0, 0..=10, 1, 1.0),
+                    Err(super::Error::InteriorNul(_))
+                ));
+            },
+        )
+        .unwrap();
     }
 }
@@ -0,0 +1,85 @@
+use crate::sys;
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Vec2 {
+    pub x: f32,
+    pub y: f32,
+}
+impl Vec2 {
+    #[inline]
+    pub fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+}
+impl From<(f32, f32)> for Vec2 {
+    fn from(val: (f32, f32)) -> Self {
+        Self::new(val.0, val.1)
+    }
+}
+impl From<[f32; 2]> for Vec2 {
+    fn from(val: [f32; 2]) -> Self {
+        Self::new(val[0], val[1])
+    }
+}
+impl From<sys::nk_vec2> for Vec2 {
+    fn from(val: sys::nk_vec2) -> Self {
+        Self::new(val.x, val.y)
+    }
+}
+impl Into<sys::nk_vec2> for Vec2 {
+    fn into(self) -> sys::nk_vec2 {
+        sys::nk_vec2 {
+            x: self.x,
+            y: self.y,
+        }
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Rect {
+    pub x: f32,
+    pub y: f32,
+    pub w: f32,
+    pub h: f32,
+}
+impl Rect {
+    #[inline]
+    pub fn new(x: f32, y: f32, w: f32, h: f32) -> Self {
+        Self { x, y, w, h }
+    }
+
+    #[inline]
+    pub fn position(&self) -> Vec2 {
+        Vec2::new(self.x, self.y)
+    }
+
+    #[inline]
+    pub fn size(&self) -> Vec2 {
+        Vec2::new(self.w, self.h)
+    }
+}
+impl From<(f32, f32, f32, f32)> for Rect {
+    fn from(val: (f32, f32, f32, f32)) -> Self {
+        Self::new(val.0, val.1, val.2, val.3)
+    }
+}
+impl From<[f32; 4]> for Rect {
+    fn from(val: [f32; 4]) -> Self {
+        Self::new(val[0], val[1], val[2], val[3])
+    }
+}
+impl From<sys::nk_rect> for Rect {
+    fn from(val: sys::nk_rect) -> Self {
+        Self::new(val.x, val.y, val.w, val.h)
+    }
+}
+impl Into<sys::nk_rect> for Rect {
+    fn into(self) -> sys::nk_rect {
+        sys::nk_rect {
+            x: self.x,
+            y: self.y,
+            w: self.w,
+            h: self.h,
+        }
+    }
+}
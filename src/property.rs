@@ -0,0 +1,179 @@
+use crate::{c_str, draw::WindowScope, sys, Error, Nuklear, NuklearScope, NuklearType};
+use std::{ffi::CStr, ops::RangeInclusive};
+
+/// A number that can be edited with a property.
+pub trait PropertyValue: Copy + PartialEq {
+    fn property(
+        ui: &NuklearScope<'_, WindowScope>,
+        name: &CStr,
+        value: &mut Self,
+        range: RangeInclusive<Self>,
+        step: Self,
+        inc_per_pixel: f32,
+    );
+
+    fn property_value(
+        ui: &NuklearScope<'_, WindowScope>,
+        name: &CStr,
+        value: Self,
+        range: RangeInclusive<Self>,
+        step: Self,
+        inc_per_pixel: f32,
+    ) -> Self;
+}
+
+macro_rules! impl_property_value {
+    ($ty:ty, $property:ident, $property_value:ident) => {
+        impl PropertyValue for $ty {
+            fn property(
+                ui: &NuklearScope<'_, WindowScope>,
+                name: &CStr,
+                value: &mut Self,
+                range: RangeInclusive<Self>,
+                step: Self,
+                inc_per_pixel: f32,
+            ) {
+                unsafe {
+                    sys::$property(
+                        ui.as_ptr(),
+                        name.as_ptr(),
+                        *range.start(),
+                        value,
+                        *range.end(),
+                        step,
+                        inc_per_pixel,
+                    )
+                }
+            }
+
+            fn property_value(
+                ui: &NuklearScope<'_, WindowScope>,
+                name: &CStr,
+                value: Self,
+                range: RangeInclusive<Self>,
+                step: Self,
+                inc_per_pixel: f32,
+            ) -> Self {
+                unsafe {
+                    sys::$property_value(
+                        ui.as_ptr(),
+                        name.as_ptr(),
+                        *range.start(),
+                        value,
+                        *range.end(),
+                        step,
+                        inc_per_pixel,
+                    )
+                }
+            }
+        }
+    };
+}
+
+impl_property_value!(i32, nk_property_int, nk_propertyi);
+impl_property_value!(f32, nk_property_float, nk_propertyf);
+impl_property_value!(f64, nk_property_double, nk_propertyd);
+
+impl Nuklear {
+    // Nuklear identifies a property by its name, unless the name starts with `#`: then the `#` is
+    // not shown, and the property is identified by its order within the window instead. Always
+    // prefixing one shows `name` as given, and keeps properties sharing a label (e.g. "X:") apart.
+    // The name is built in a buffer shared by all properties, as Nuklear only reads it during the
+    // call.
+    fn with_property_name<T, F>(&self, name: &str, f: F) -> Result<T, Error>
+    where
+        F: FnOnce(&CStr) -> T,
+    {
+        if name.contains('\0') {
+            // Only allocates to build the error
+            return Err(c_str(name).unwrap_err());
+        }
+
+        let mut buffer = self.property_name.borrow_mut();
+        buffer.clear();
+        buffer.push(b'#');
+        buffer.extend_from_slice(name.as_bytes());
+        buffer.push(0);
+
+        Ok((f)(unsafe { CStr::from_bytes_with_nul_unchecked(&buffer) }))
+    }
+}
+
+// A lone `#`, which leaves an empty label
+fn hidden_name() -> &'static CStr {
+    unsafe { CStr::from_bytes_with_nul_unchecked(b"#\0") }
+}
+
+impl<'a> NuklearScope<'a, WindowScope> {
+    /// A field showing `name` and `value`, which is edited by dragging, clicking its arrows by
+    /// `step`, or typing a number. Dragging changes `value` by `inc_per_pixel` per pixel. Returns
+    /// whether `value` changed.
+    pub fn property<S, T>(
+        &self,
+        name: S,
+        value: &mut T,
+        range: RangeInclusive<T>,
+        step: T,
+        inc_per_pixel: f32,
+    ) -> Result<bool, Error>
+    where
+        S: AsRef<str>,
+        T: PropertyValue,
+    {
+        let previous = *value;
+        self.with_property_name(name.as_ref(), |name| {
+            T::property(self, name, value, range, step, inc_per_pixel)
+        })?;
+
+        Ok(*value != previous)
+    }
+
+    /// Like [`NuklearScope::property`], without showing a name.
+    pub fn property_unlabeled<T>(
+        &self,
+        value: &mut T,
+        range: RangeInclusive<T>,
+        step: T,
+        inc_per_pixel: f32,
+    ) -> bool
+    where
+        T: PropertyValue,
+    {
+        let previous = *value;
+        T::property(self, hidden_name(), value, range, step, inc_per_pixel);
+
+        *value != previous
+    }
+
+    /// A property drawn with `value`. Returns its value after this frame's input.
+    pub fn property_value<S, T>(
+        &self,
+        name: S,
+        value: T,
+        range: RangeInclusive<T>,
+        step: T,
+        inc_per_pixel: f32,
+    ) -> Result<T, Error>
+    where
+        S: AsRef<str>,
+        T: PropertyValue,
+    {
+        self.with_property_name(name.as_ref(), |name| {
+            T::property_value(self, name, value, range, step, inc_per_pixel)
+        })
+    }
+
+    /// Like [`NuklearScope::property_value`], without showing a name.
+    pub fn property_value_unlabeled<T>(
+        &self,
+        value: T,
+        range: RangeInclusive<T>,
+        step: T,
+        inc_per_pixel: f32,
+    ) -> T
+    where
+        T: PropertyValue,
+    {
+        T::property_value(self, hidden_name(), value, range, step, inc_per_pixel)
+    }
+}
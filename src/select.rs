@@ -0,0 +1,136 @@
+use crate::{
+    draw::{Image, Symbol, WindowScope},
+    sys,
+    text::TextAlign,
+    text_ptr, NuklearScope, NuklearType,
+};
+use std::os::raw::c_int;
+
+impl<'a> NuklearScope<'a, WindowScope> {
+    /// A label toggling `selected` when clicked, highlighted while selected. Returns whether it was
+    /// toggled.
+    pub fn selectable_label<S>(&self, label: S, align: TextAlign, selected: &mut bool) -> bool
+    where
+        S: AsRef<str>,
+    {
+        let (label, len) = text_ptr(label.as_ref());
+        let mut value = c_int::from(*selected);
+        let changed = unsafe {
+            sys::nk_selectable_text(self.as_ptr(), label, len, align.bits(), &mut value) != 0
+        };
+        *selected = value != 0;
+
+        changed
+    }
+
+    pub fn selectable_image_label<S>(
+        &self,
+        image: Image,
+        label: S,
+        align: TextAlign,
+        selected: &mut bool,
+    ) -> bool
+    where
+        S: AsRef<str>,
+    {
+        let (label, len) = text_ptr(label.as_ref());
+        let mut value = c_int::from(*selected);
+        let changed = unsafe {
+            sys::nk_selectable_image_text(
+                self.as_ptr(),
+                image.into(),
+                label,
+                len,
+                align.bits(),
+                &mut value,
+            ) != 0
+        };
+        *selected = value != 0;
+
+        changed
+    }
+
+    pub fn selectable_symbol_label<S>(
+        &self,
+        symbol: Symbol,
+        label: S,
+        align: TextAlign,
+        selected: &mut bool,
+    ) -> bool
+    where
+        S: AsRef<str>,
+    {
+        let (label, len) = text_ptr(label.as_ref());
+        let mut value = c_int::from(*selected);
+        let changed = unsafe {
+            sys::nk_selectable_symbol_text(
+                self.as_ptr(),
+                symbol.into(),
+                label,
+                len,
+                align.bits(),
+                &mut value,
+            ) != 0
+        };
+        *selected = value != 0;
+
+        changed
+    }
+
+    /// A selectable label drawn as `selected`. Returns its state after this frame's input.
+    pub fn select_label<S>(&self, label: S, align: TextAlign, selected: bool) -> bool
+    where
+        S: AsRef<str>,
+    {
+        let (label, len) = text_ptr(label.as_ref());
+        unsafe {
+            sys::nk_select_text(self.as_ptr(), label, len, align.bits(), selected.into()) != 0
+        }
+    }
+
+    pub fn select_image_label<S>(
+        &self,
+        image: Image,
+        label: S,
+        align: TextAlign,
+        selected: bool,
+    ) -> bool
+    where
+        S: AsRef<str>,
+    {
+        let (label, len) = text_ptr(label.as_ref());
+        unsafe {
+            sys::nk_select_image_text(
+                self.as_ptr(),
+                image.into(),
+                label,
+                len,
+                align.bits(),
+                selected.into(),
+            ) != 0
+        }
+    }
+
+    pub fn select_symbol_label<S>(
+        &self,
+        symbol: Symbol,
+        label: S,
+        align: TextAlign,
+        selected: bool,
+    ) -> bool
+    where
+        S: AsRef<str>,
+    {
+        let (label, len) = text_ptr(label.as_ref());
+        unsafe {
+            sys::nk_select_symbol_text(
+                self.as_ptr(),
+                symbol.into(),
+                label,
+                len,
+                align.bits(),
+                selected.into(),
+            ) != 0
+        }
+    }
+}
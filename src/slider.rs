@@ -0,0 +1,125 @@
+use crate::{draw::WindowScope, sys, NuklearScope, NuklearType};
+use std::ops::RangeInclusive;
+
+/// A number that can be edited with a slider. Nuklear only has integer and `f32` sliders, so
+/// `f64` values go through `f32`.
+pub trait SliderValue: Copy {
+    fn slider(
+        ui: &NuklearScope<'_, WindowScope>,
+        value: &mut Self,
+        range: RangeInclusive<Self>,
+        step: Self,
+    ) -> bool;
+
+    fn slide(
+        ui: &NuklearScope<'_, WindowScope>,
+        value: Self,
+        range: RangeInclusive<Self>,
+        step: Self,
+    ) -> Self;
+}
+
+impl SliderValue for i32 {
+    fn slider(
+        ui: &NuklearScope<'_, WindowScope>,
+        value: &mut Self,
+        range: RangeInclusive<Self>,
+        step: Self,
+    ) -> bool {
+        unsafe { sys::nk_slider_int(ui.as_ptr(), *range.start(), value, *range.end(), step) != 0 }
+    }
+
+    fn slide(
+        ui: &NuklearScope<'_, WindowScope>,
+        value: Self,
+        range: RangeInclusive<Self>,
+        step: Self,
+    ) -> Self {
+        unsafe { sys::nk_slide_int(ui.as_ptr(), *range.start(), value, *range.end(), step) }
+    }
+}
+
+impl SliderValue for f32 {
+    fn slider(
+        ui: &NuklearScope<'_, WindowScope>,
+        value: &mut Self,
+        range: RangeInclusive<Self>,
+        step: Self,
+    ) -> bool {
+        unsafe { sys::nk_slider_float(ui.as_ptr(), *range.start(), value, *range.end(), step) != 0 }
+    }
+
+    fn slide(
+        ui: &NuklearScope<'_, WindowScope>,
+        value: Self,
+        range: RangeInclusive<Self>,
+        step: Self,
+    ) -> Self {
+        unsafe { sys::nk_slide_float(ui.as_ptr(), *range.start(), value, *range.end(), step) }
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+impl SliderValue for f64 {
+    fn slider(
+        ui: &NuklearScope<'_, WindowScope>,
+        value: &mut Self,
+        range: RangeInclusive<Self>,
+        step: Self,
+    ) -> bool {
+        let mut narrowed = *value as f32;
+        let range = (*range.start() as f32)..=(*range.end() as f32);
+
+        // Only write back on change, so values are not rounded to `f32` just by being shown
+        if f32::slider(ui, &mut narrowed, range, step as f32) {
+            *value = f64::from(narrowed);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn slide(
+        ui: &NuklearScope<'_, WindowScope>,
+        value: Self,
+        range: RangeInclusive<Self>,
+        step: Self,
+    ) -> Self {
+        let mut changed = value;
+        if Self::slider(ui, &mut changed, range, step) {
+            changed
+        } else {
+            value
+        }
+    }
+}
+
+impl<'a> NuklearScope<'a, WindowScope> {
+    /// A slider moving `value` within `range` in increments of `step`. Returns whether `value`
+    /// changed.
+    pub fn slider<T>(&self, value: &mut T, range: RangeInclusive<T>, step: T) -> bool
+    where
+        T: SliderValue,
+    {
+        T::slider(self, value, range, step)
+    }
+
+    /// A slider drawn at `value`. Returns its value after this frame's input.
+    pub fn slide<T>(&self, value: T, range: RangeInclusive<T>, step: T) -> T
+    where
+        T: SliderValue,
+    {
+        T::slide(self, value, range, step)
+    }
+
+    /// A progress bar filled to `value` out of `max`, which can be dragged if `modifiable`.
+    /// Returns whether `value` changed.
+    pub fn progress(&self, value: &mut usize, max: usize, modifiable: bool) -> bool {
+        unsafe { sys::nk_progress(self.as_ptr(), value, max, modifiable.into()) != 0 }
+    }
+
+    /// A progress bar filled to `value` out of `max`. Returns its value after this frame's input.
+    pub fn prog(&self, value: usize, max: usize, modifiable: bool) -> usize {
+        unsafe { sys::nk_prog(self.as_ptr(), value, max, modifiable.into()) }
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{sys, Nuklear, NuklearType};
+
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Cursor {
+    Arrow = sys::nk_style_cursor_NK_CURSOR_ARROW,
+    Text = sys::nk_style_cursor_NK_CURSOR_TEXT,
+    Move = sys::nk_style_cursor_NK_CURSOR_MOVE,
+    ResizeVertical = sys::nk_style_cursor_NK_CURSOR_RESIZE_VERTICAL,
+    ResizeHorizontal = sys::nk_style_cursor_NK_CURSOR_RESIZE_HORIZONTAL,
+    ResizeTopLeftDownRight = sys::nk_style_cursor_NK_CURSOR_RESIZE_TOP_LEFT_DOWN_RIGHT,
+    ResizeTopRightDownLeft = sys::nk_style_cursor_NK_CURSOR_RESIZE_TOP_RIGHT_DOWN_LEFT,
+}
+impl Cursor {
+    pub const ALL: [Cursor; sys::nk_style_cursor_NK_CURSOR_COUNT as usize] = [
+        Cursor::Arrow,
+        Cursor::Text,
+        Cursor::Move,
+        Cursor::ResizeVertical,
+        Cursor::ResizeHorizontal,
+        Cursor::ResizeTopLeftDownRight,
+        Cursor::ResizeTopRightDownLeft,
+    ];
+}
+impl Into<sys::nk_style_cursor> for Cursor {
+    fn into(self) -> sys::nk_style_cursor {
+        self as sys::nk_style_cursor
+    }
+}
+
+impl Nuklear {
+    // Points the style at the cursors baked into the font atlas, which Nuklear needs to report the
+    // cursor it wants. Nuklear only draws them itself once enabled with `set_draw_cursor`.
+    pub(crate) fn load_cursors(&mut self) {
+        let cursors = unsafe { (*self.font_image.atlas().as_ptr()).cursors.as_mut_ptr() };
+
+        unsafe {
+            sys::nk_style_load_all_cursors(self.as_ptr(), cursors);
+        }
+        self.set_draw_cursor(false);
+    }
+
+    /// The cursor Nuklear wants to show for the last frame, e.g. a text cursor over an edit field.
+    #[inline]
+    pub fn cursor(&self) -> Cursor {
+        self.cursor.get()
+    }
+
+    // The cursor set by the widgets of the current frame
+    pub(crate) fn active_cursor(&self) -> Cursor {
+        let inner = self.inner.borrow();
+
+        Cursor::ALL
+            .iter()
+            .copied()
+            .find(|cursor| {
+                let loaded = inner.style.cursors[*cursor as usize];
+                !loaded.is_null() && loaded == inner.style.cursor_active
+            })
+            .unwrap_or(Cursor::Arrow)
+    }
+
+    /// Whether Nuklear draws the cursor itself, from the images baked into the font atlas.
+    pub fn draws_cursor(&self) -> bool {
+        self.inner.borrow().style.cursor_visible != 0
+    }
+
+    /// Enables or disables Nuklear drawing the cursor itself. When enabled, the OS cursor should
+    /// be hidden.
+    pub fn set_draw_cursor(&mut self, draw: bool) {
+        if draw {
+            unsafe { sys::nk_style_show_cursor(self.as_ptr()) };
+        } else {
+            unsafe { sys::nk_style_hide_cursor(self.as_ptr()) };
+        }
+    }
+
+    /// A copy of the context's button style, to modify for the `*_styled` buttons.
+    pub fn button_style(&self) -> sys::nk_style_button {
+        self.inner.borrow().style.button
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Cursor;
+    use crate::{alloc, draw::PanelFlags, sys, tests::test_context, Nuklear, NuklearType};
+    use std::os::raw::c_char;
+
+    fn edit_frame(context: &mut Nuklear) {
+        let mut buffer = [0 as c_char; 16];
+
+        context
+            .frame()
+            .begin(
+                "Test",
+                (0.0, 0.0, 200.0, 200.0),
+                PanelFlags::BORDER,
+                |ctx| {
+                    ctx.layout_row_dynamic(30.0, 1);
+                    unsafe {
+                        sys::nk_edit_string_zero_terminated(
+                            ctx.as_ptr(),
+                            sys::nk_edit_types_NK_EDIT_FIELD,
+                            buffer.as_mut_ptr(),
+                            16,
+                            Some(sys::nk_filter_default),
+                        );
+                    }
+                },
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn cursor_outlives_frame() {
+        let mut context = test_context(alloc::global::create());
+
+        drop(context.begin_input());
+        edit_frame(&mut context);
+        assert_eq!(context.cursor(), Cursor::Arrow);
+
+        // Hover the edit field in the first row
+        context.begin_input().input_motion(100, 15);
+        edit_frame(&mut context);
+        assert_eq!(context.cursor(), Cursor::Text);
+
+        context.begin_input().input_motion(100, 150);
+        edit_frame(&mut context);
+        assert_eq!(context.cursor(), Cursor::Arrow);
+    }
+}
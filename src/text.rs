@@ -0,0 +1,53 @@
+use crate::{color::Color, draw::WindowScope, sys, text_ptr, NuklearScope, NuklearType};
+
+bitflags::bitflags! {
+    pub struct TextAlign: sys::nk_flags {
+        const LEFT = sys::nk_text_align_NK_TEXT_ALIGN_LEFT;
+        const CENTERED = sys::nk_text_align_NK_TEXT_ALIGN_CENTERED;
+        const RIGHT = sys::nk_text_align_NK_TEXT_ALIGN_RIGHT;
+        const TOP = sys::nk_text_align_NK_TEXT_ALIGN_TOP;
+        const MIDDLE = sys::nk_text_align_NK_TEXT_ALIGN_MIDDLE;
+        const BOTTOM = sys::nk_text_align_NK_TEXT_ALIGN_BOTTOM;
+
+        const MIDDLE_LEFT = sys::nk_text_alignment_NK_TEXT_LEFT;
+        const MIDDLE_CENTERED = sys::nk_text_alignment_NK_TEXT_CENTERED;
+        const MIDDLE_RIGHT = sys::nk_text_alignment_NK_TEXT_RIGHT;
+    }
+}
+
+impl<'a> NuklearScope<'a, WindowScope> {
+    pub fn label<S>(&self, text: S, align: TextAlign)
+    where
+        S: AsRef<str>,
+    {
+        let (text, len) = text_ptr(text.as_ref());
+        unsafe { sys::nk_text(self.as_ptr(), text, len, align.bits) }
+    }
+
+    pub fn label_colored<S, C>(&self, text: S, align: TextAlign, color: C)
+    where
+        S: AsRef<str>,
+        C: Into<Color>,
+    {
+        let (text, len) = text_ptr(text.as_ref());
+        unsafe { sys::nk_text_colored(self.as_ptr(), text, len, align.bits, color.into().into()) }
+    }
+
+    /// A label wrapping its text onto as many lines as its row allows.
+    pub fn label_wrap<S>(&self, text: S)
+    where
+        S: AsRef<str>,
+    {
+        let (text, len) = text_ptr(text.as_ref());
+        unsafe { sys::nk_text_wrap(self.as_ptr(), text, len) }
+    }
+
+    pub fn label_colored_wrap<S, C>(&self, text: S, color: C)
+    where
+        S: AsRef<str>,
+        C: Into<Color>,
+    {
+        let (text, len) = text_ptr(text.as_ref());
+        unsafe { sys::nk_text_wrap_colored(self.as_ptr(), text, len, color.into().into()) }
+    }
+}
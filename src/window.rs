@@ -0,0 +1,330 @@
+use crate::{
+    c_str,
+    math::{Rect, Vec2},
+    sys, Error, Nuklear, NuklearType,
+};
+use std::ffi::CString;
+
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CollapseState {
+    Minimized = sys::nk_collapse_states_NK_MINIMIZED,
+    Maximized = sys::nk_collapse_states_NK_MAXIMIZED,
+}
+impl Into<sys::nk_collapse_states> for CollapseState {
+    fn into(self) -> sys::nk_collapse_states {
+        self as sys::nk_collapse_states
+    }
+}
+
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ShowState {
+    Hidden = sys::nk_show_states_NK_HIDDEN,
+    Shown = sys::nk_show_states_NK_SHOWN,
+}
+impl Into<sys::nk_show_states> for ShowState {
+    fn into(self) -> sys::nk_show_states {
+        self as sys::nk_show_states
+    }
+}
+
+/// The state of a window after it was begun.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum WindowState {
+    /// The window is shown with its content
+    Visible,
+    /// The window is minimized to its header
+    Collapsed,
+    /// The window was hidden by [`Window::show`]
+    Hidden,
+    /// The window was closed by its close button, and stays hidden until shown again with
+    /// [`Window::show`], or by [`Window::close`], after which it is recreated when begun again
+    Closed,
+}
+impl WindowState {
+    pub(crate) fn from_flags(flags: sys::nk_flags) -> Self {
+        if flags & sys::nk_window_flags_NK_WINDOW_CLOSED != 0 {
+            WindowState::Closed
+        } else if flags & sys::nk_window_flags_NK_WINDOW_HIDDEN != 0 {
+            WindowState::Hidden
+        } else if flags & sys::nk_window_flags_NK_WINDOW_MINIMIZED != 0 {
+            WindowState::Collapsed
+        } else {
+            WindowState::Visible
+        }
+    }
+
+    #[inline]
+    pub fn is_visible(self) -> bool {
+        self == WindowState::Visible
+    }
+
+    #[inline]
+    pub fn is_open(self) -> bool {
+        self != WindowState::Closed
+    }
+}
+
+/// A window of a context, looked up by the name it was begun with. The window does not need to
+/// exist; queries on a missing window return `None` or `false`, and changes to it are ignored.
+pub struct Window<'a> {
+    context: &'a Nuklear,
+    name: CString,
+}
+
+impl Nuklear {
+    // The state of the window that was just begun, before `nk_end` is called
+    pub(crate) fn current_window_state(&self) -> WindowState {
+        let inner = self.inner.borrow();
+        let window = unsafe { &*inner.current };
+        let mut closed_windows = self.closed_windows.borrow_mut();
+
+        // Hidden windows return from `nk_begin` before their panel is created
+        if window.layout.is_null() {
+            return match WindowState::from_flags(window.flags) {
+                WindowState::Hidden if closed_windows.contains(&window.name) => WindowState::Closed,
+                state => state,
+            };
+        }
+
+        // The header buttons only change the flags of the panel, which are copied to the window
+        // in `nk_end`. The close button hides the window.
+        let flags = unsafe { (*window.layout).flags };
+        let hidden = sys::nk_window_flags_NK_WINDOW_HIDDEN;
+        if flags & hidden != 0 && window.flags & hidden == 0 {
+            closed_windows.insert(window.name);
+            return WindowState::Closed;
+        }
+
+        closed_windows.remove(&window.name);
+        WindowState::from_flags(flags)
+    }
+
+    pub fn window<S>(&self, name: S) -> Result<Window<'_>, Error>
+    where
+        S: AsRef<str>,
+    {
+        Ok(Window {
+            context: self,
+            name: c_str(name.as_ref())?,
+        })
+    }
+}
+
+impl<'a> Window<'a> {
+    fn find(&self) -> Option<&sys::nk_window> {
+        unsafe { sys::nk_window_find(self.context.as_ptr(), self.name.as_ptr()).as_ref() }
+    }
+
+    pub fn exists(&self) -> bool {
+        self.find().is_some()
+    }
+
+    pub fn bounds(&self) -> Option<Rect> {
+        self.find().map(|window| window.bounds.into())
+    }
+
+    pub fn position(&self) -> Option<Vec2> {
+        self.bounds().map(|bounds| bounds.position())
+    }
+
+    pub fn size(&self) -> Option<Vec2> {
+        self.bounds().map(|bounds| bounds.size())
+    }
+
+    /// The scroll offset of the window's content, in pixels.
+    pub fn scroll(&self) -> Option<(u32, u32)> {
+        self.find()
+            .map(|window| (window.scrollbar.x, window.scrollbar.y))
+    }
+
+    pub fn has_focus(&self) -> bool {
+        self.find().map_or(false, |window| {
+            std::ptr::eq(window, self.context.inner.borrow().active)
+        })
+    }
+
+    pub fn is_hovered(&self) -> bool {
+        self.find().map_or(false, |window| {
+            window.flags & sys::nk_window_flags_NK_WINDOW_HIDDEN == 0
+                && unsafe {
+                    sys::nk_input_is_mouse_hovering_rect(
+                        &self.context.inner.borrow().input,
+                        window.bounds,
+                    ) != 0
+                }
+        })
+    }
+
+    pub fn is_collapsed(&self) -> bool {
+        unsafe { sys::nk_window_is_collapsed(self.context.as_ptr(), self.name.as_ptr()) != 0 }
+    }
+
+    /// Whether the window was closed by its close button or [`Window::close`], which is also the
+    /// case when it does not exist. A window closed by its close button stays closed until shown
+    /// again.
+    pub fn is_closed(&self) -> bool {
+        let closed_by_user = self.find().map_or(false, |window| {
+            window.flags & sys::nk_window_flags_NK_WINDOW_HIDDEN != 0
+                && self.context.closed_windows.borrow().contains(&window.name)
+        });
+
+        closed_by_user
+            || unsafe { sys::nk_window_is_closed(self.context.as_ptr(), self.name.as_ptr()) != 0 }
+    }
+
+    /// Whether the window is hidden, which is also the case when it does not exist.
+    pub fn is_hidden(&self) -> bool {
+        unsafe { sys::nk_window_is_hidden(self.context.as_ptr(), self.name.as_ptr()) != 0 }
+    }
+
+    pub fn is_active(&self) -> bool {
+        unsafe { sys::nk_window_is_active(self.context.as_ptr(), self.name.as_ptr()) != 0 }
+    }
+
+    pub fn set_bounds<R>(&self, bounds: R)
+    where
+        R: Into<Rect>,
+    {
+        unsafe {
+            sys::nk_window_set_bounds(
+                self.context.as_ptr(),
+                self.name.as_ptr(),
+                bounds.into().into(),
+            )
+        }
+    }
+
+    pub fn set_position<V>(&self, position: V)
+    where
+        V: Into<Vec2>,
+    {
+        unsafe {
+            sys::nk_window_set_position(
+                self.context.as_ptr(),
+                self.name.as_ptr(),
+                position.into().into(),
+            )
+        }
+    }
+
+    pub fn set_size<V>(&self, size: V)
+    where
+        V: Into<Vec2>,
+    {
+        unsafe {
+            sys::nk_window_set_size(
+                self.context.as_ptr(),
+                self.name.as_ptr(),
+                size.into().into(),
+            )
+        }
+    }
+
+    pub fn set_focus(&self) {
+        unsafe { sys::nk_window_set_focus(self.context.as_ptr(), self.name.as_ptr()) }
+    }
+
+    /// Sets the scroll offset of the window's content, in pixels.
+    pub fn set_scroll(&self, x: u32, y: u32) {
+        // `nk_window_set_scroll` only works on the current window, so look it up instead
+        let window = unsafe { sys::nk_window_find(self.context.as_ptr(), self.name.as_ptr()) };
+        if let Some(window) = unsafe { window.as_mut() } {
+            window.scrollbar.x = x;
+            window.scrollbar.y = y;
+        }
+    }
+
+    /// Closes the window. A window can not close itself while it is being built.
+    pub fn close(&self) {
+        let current = self.context.inner.borrow().current;
+        if self
+            .find()
+            .map_or(false, |window| std::ptr::eq(window, current))
+        {
+            return;
+        }
+
+        unsafe { sys::nk_window_close(self.context.as_ptr(), self.name.as_ptr()) }
+    }
+
+    pub fn collapse(&self, state: CollapseState) {
+        unsafe { sys::nk_window_collapse(self.context.as_ptr(), self.name.as_ptr(), state.into()) }
+    }
+
+    pub fn collapse_if(&self, state: CollapseState, condition: bool) {
+        unsafe {
+            sys::nk_window_collapse_if(
+                self.context.as_ptr(),
+                self.name.as_ptr(),
+                state.into(),
+                condition.into(),
+            )
+        }
+    }
+
+    pub fn show(&self, state: ShowState) {
+        unsafe { sys::nk_window_show(self.context.as_ptr(), self.name.as_ptr(), state.into()) }
+    }
+
+    pub fn show_if(&self, state: ShowState, condition: bool) {
+        unsafe {
+            sys::nk_window_show_if(
+                self.context.as_ptr(),
+                self.name.as_ptr(),
+                state.into(),
+                condition.into(),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ShowState, WindowState};
+    use crate::{alloc, draw::PanelFlags, input::Button, tests::test_context, Nuklear};
+
+    fn begin_closable(context: &mut Nuklear) -> WindowState {
+        context
+            .frame()
+            .begin(
+                "Test",
+                (0.0, 0.0, 200.0, 200.0),
+                PanelFlags::BORDER | PanelFlags::CLOSABLE,
+                |_| {},
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn close_button() {
+        let mut context = test_context(alloc::global::create());
+
+        drop(context.begin_input());
+        assert_eq!(begin_closable(&mut context), WindowState::Visible);
+
+        {
+            // The close button is at the right of the header, which is as high as the default
+            // font (13) plus its padding (2 * 4) and the label's padding (2 * 4).
+            let mut input = context.begin_input();
+            input.input_motion(185, 14);
+            input.input_button(185, 14, Button::Left, true);
+        }
+        let state = begin_closable(&mut context);
+        assert_eq!(state, WindowState::Closed);
+        assert!(!state.is_open());
+        assert!(context.window("Test").unwrap().is_closed());
+
+        {
+            let mut input = context.begin_input();
+            input.input_button(185, 14, Button::Left, false);
+        }
+        assert_eq!(begin_closable(&mut context), WindowState::Closed);
+
+        // Showing the window again opens it
+        context.window("Test").unwrap().show(ShowState::Shown);
+        assert!(!context.window("Test").unwrap().is_closed());
+        assert_eq!(begin_closable(&mut context), WindowState::Visible);
+    }
+}
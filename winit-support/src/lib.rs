@@ -1,53 +1,586 @@
-use nukly::{input::Button, input::Scope as Input, Nuklear};
-use std::marker::PhantomData;
+use nukly::{
+    input::{Button, Key},
+    style::Cursor,
+    Nuklear,
+};
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    time::{Duration, Instant},
+};
 use winit::{
     dpi::LogicalPosition,
-    event::{DeviceEvent, ElementState, Event, MouseButton, WindowEvent},
-    window::Window,
+    event::{
+        ElementState, Event, KeyboardInput, ModifiersState, MouseButton, MouseScrollDelta,
+        VirtualKeyCode, WindowEvent,
+    },
+    window::{CursorIcon, Window},
 };
 
+/// Maps a winit mouse button to its Nuklear equivalent. Nuklear has no notion of extra mouse
+/// buttons, so those map to `None`.
 #[inline(always)]
-pub fn winit_to_nk_button(button: MouseButton) -> Button {
+pub fn winit_to_nk_button(button: MouseButton) -> Option<Button> {
     match button {
-        MouseButton::Left => Button::Left,
-        MouseButton::Right => Button::Left,
-        MouseButton::Middle => Button::Left,
-        _ => unimplemented!(),
+        MouseButton::Left => Some(Button::Left),
+        MouseButton::Right => Some(Button::Right),
+        MouseButton::Middle => Some(Button::Middle),
+        MouseButton::Other(_) => None,
+    }
+}
+
+#[inline(always)]
+pub fn nk_to_winit_cursor(cursor: Cursor) -> CursorIcon {
+    match cursor {
+        Cursor::Arrow => CursorIcon::Default,
+        Cursor::Text => CursorIcon::Text,
+        Cursor::Move => CursorIcon::Move,
+        Cursor::ResizeVertical => CursorIcon::NsResize,
+        Cursor::ResizeHorizontal => CursorIcon::EwResize,
+        Cursor::ResizeTopLeftDownRight => CursorIcon::NwseResize,
+        Cursor::ResizeTopRightDownLeft => CursorIcon::NeswResize,
+    }
+}
+
+/// Maps a winit key, along with the currently held modifiers, to the Nuklear key it triggers.
+/// Shift and control are not mapped here, as they are driven by `ModifiersChanged`.
+pub fn winit_to_nk_key(keycode: VirtualKeyCode, modifiers: ModifiersState) -> Option<Key> {
+    if modifiers.ctrl() {
+        let shortcut = match keycode {
+            VirtualKeyCode::C => Some(Key::Copy),
+            VirtualKeyCode::X => Some(Key::Cut),
+            VirtualKeyCode::V => Some(Key::Paste),
+            VirtualKeyCode::Z if modifiers.shift() => Some(Key::TextRedo),
+            VirtualKeyCode::Z => Some(Key::TextUndo),
+            VirtualKeyCode::Y => Some(Key::TextRedo),
+            VirtualKeyCode::A => Some(Key::TextSelectAll),
+            VirtualKeyCode::Left => Some(Key::TextWordLeft),
+            VirtualKeyCode::Right => Some(Key::TextWordRight),
+            VirtualKeyCode::Home => Some(Key::TextStart),
+            VirtualKeyCode::End => Some(Key::TextEnd),
+            _ => None,
+        };
+
+        if shortcut.is_some() {
+            return shortcut;
+        }
+    }
+
+    match keycode {
+        VirtualKeyCode::Delete => Some(Key::Delete),
+        VirtualKeyCode::Return | VirtualKeyCode::NumpadEnter => Some(Key::Enter),
+        VirtualKeyCode::Tab => Some(Key::Tab),
+        VirtualKeyCode::Back => Some(Key::Backspace),
+        VirtualKeyCode::Up => Some(Key::Up),
+        VirtualKeyCode::Down => Some(Key::Down),
+        VirtualKeyCode::Left => Some(Key::Left),
+        VirtualKeyCode::Right => Some(Key::Right),
+        VirtualKeyCode::Home => Some(Key::TextLineStart),
+        VirtualKeyCode::End => Some(Key::TextLineEnd),
+        VirtualKeyCode::PageUp => Some(Key::ScrollUp),
+        VirtualKeyCode::PageDown => Some(Key::ScrollDown),
+        _ => None,
+    }
+}
+
+// Input is gathered over the whole frame and only fed to Nuklear in `finish`, as Nuklear expects
+// all of a frame's input between a single `nk_input_begin`/`nk_input_end` pair.
+enum PendingInput {
+    Motion(i32, i32),
+    Button(Button, i32, i32, bool),
+    Scroll(f32, f32),
+    Key(Key, bool),
+    Char(char),
+}
+
+// Whether a press or release of `key` is consumed. A press is consumed if the UI `wants` it, and
+// the release, as well as any repeats in between, follow the first press.
+fn track_consumed<K, F>(consumed: &mut HashMap<K, bool>, key: K, pressed: bool, wants: F) -> bool
+where
+    K: Eq + Hash,
+    F: FnOnce() -> bool,
+{
+    if pressed {
+        *consumed.entry(key).or_insert_with(wants)
+    } else {
+        consumed.remove(&key).unwrap_or(false)
     }
 }
 
+/// How many pixels of a `MouseScrollDelta::PixelDelta` make up a single line of scrolling.
+pub const PIXELS_PER_SCROLL_LINE: f64 = 20.0;
+
 pub struct NuklyWindowEventHandler {
     last_mouse_position: LogicalPosition<f64>,
+    scale_factor: f64,
+    pending: Vec<PendingInput>,
+    double_click_time: Duration,
+    double_click_distance: f64,
+    last_click: Option<(Instant, LogicalPosition<f64>)>,
+    double_click_down: bool,
+    modifiers: ModifiersState,
+    // The Nuklear key each held winit key was mapped to when pressed, so the same one is released
+    // even if the modifiers changed in between.
+    keys_down: HashMap<VirtualKeyCode, Key>,
+    // The cursor last set on the window, and whether it was hidden as Nuklear draws its own
+    cursor: Option<(Cursor, bool)>,
+    // Whether the press of each held button or key was consumed, which decides for its release
+    buttons_consumed: HashMap<MouseButton, bool>,
+    keys_consumed: HashMap<VirtualKeyCode, bool>,
 }
 impl Default for NuklyWindowEventHandler {
     fn default() -> Self {
         Self {
             last_mouse_position: LogicalPosition { x: 0.0, y: 0.0 },
+            scale_factor: 1.0,
+            pending: Vec::default(),
+            double_click_time: Duration::from_millis(500),
+            double_click_distance: 4.0,
+            last_click: None,
+            double_click_down: false,
+            modifiers: ModifiersState::default(),
+            keys_down: HashMap::default(),
+            cursor: None,
+            buttons_consumed: HashMap::default(),
+            keys_consumed: HashMap::default(),
         }
     }
 }
 impl NuklyWindowEventHandler {
-    pub fn handle_event<T>(&mut self, context: &mut Nuklear, event: &Event<T>) {
+    /// Sets the initial scale factor of the window, as winit does not always send a
+    /// `ScaleFactorChanged` event on startup.
+    pub fn with_scale_factor(mut self, scale_factor: f64) -> Self {
+        self.scale_factor = scale_factor;
+        self
+    }
+
+    /// The scale factor of the window, which converts Nuklear's logical coordinates into the
+    /// physical pixels the renderer draws and scissors in.
+    #[inline]
+    pub fn scale_factor(&self) -> f64 {
+        self.scale_factor
+    }
+
+    /// Sets the longest time between two left clicks for them to count as a double click.
+    pub fn with_double_click_time(mut self, time: Duration) -> Self {
+        self.double_click_time = time;
+        self
+    }
+
+    /// Sets how far apart, in logical pixels, two left clicks may be to count as a double click.
+    pub fn with_double_click_distance(mut self, distance: f64) -> Self {
+        self.double_click_distance = distance;
+        self
+    }
+
+    fn handle_modifiers(&mut self, modifiers: ModifiersState) {
+        if modifiers.shift() != self.modifiers.shift() {
+            self.pending
+                .push(PendingInput::Key(Key::Shift, modifiers.shift()));
+        }
+        if modifiers.ctrl() != self.modifiers.ctrl() {
+            self.pending
+                .push(PendingInput::Key(Key::Ctrl, modifiers.ctrl()));
+        }
+
+        self.modifiers = modifiers;
+    }
+
+    fn handle_key(&mut self, keycode: VirtualKeyCode, pressed: bool) {
+        if pressed {
+            if let Some(key) = winit_to_nk_key(keycode, self.modifiers) {
+                // Key repeats arrive as further presses, so release the key first for Nuklear to
+                // see another press.
+                if let Some(previous) = self.keys_down.insert(keycode, key) {
+                    self.pending.push(PendingInput::Key(previous, false));
+                }
+                self.pending.push(PendingInput::Key(key, true));
+            }
+        } else if let Some(key) = self.keys_down.remove(&keycode) {
+            self.pending.push(PendingInput::Key(key, false));
+        }
+    }
+
+    fn handle_char(&mut self, c: char) {
+        // Control characters (enter, backspace, tab, ...) and control shortcuts are already sent
+        // as keys. Control + alt is AltGr on some platforms, which does produce printable text.
+        if c.is_control() || (self.modifiers.ctrl() && !self.modifiers.alt()) {
+            return;
+        }
+
+        self.pending.push(PendingInput::Char(c));
+    }
+
+    fn handle_double_click(&mut self, pressed: bool, now: Instant) {
+        let position = self.last_mouse_position;
+
+        if pressed {
+            let is_double = self.last_click.map_or(false, |(time, last)| {
+                let (dx, dy) = (position.x - last.x, position.y - last.y);
+                now.duration_since(time) <= self.double_click_time
+                    && (dx * dx + dy * dy).sqrt() <= self.double_click_distance
+            });
+
+            if is_double {
+                // A third click starts counting anew
+                self.last_click = None;
+                self.double_click_down = true;
+                self.pending.push(PendingInput::Button(
+                    Button::Double,
+                    position.x as i32,
+                    position.y as i32,
+                    true,
+                ));
+            } else {
+                self.last_click = Some((now, position));
+            }
+        } else if self.double_click_down {
+            self.double_click_down = false;
+            self.pending.push(PendingInput::Button(
+                Button::Double,
+                position.x as i32,
+                position.y as i32,
+                false,
+            ));
+        }
+    }
+
+    // Releases only reach the focused window, so anything still held is released on losing focus
+    fn handle_focus_lost(&mut self) {
+        for (_, key) in self.keys_down.drain() {
+            self.pending.push(PendingInput::Key(key, false));
+        }
+        self.handle_modifiers(ModifiersState::empty());
+        self.buttons_consumed.clear();
+        self.keys_consumed.clear();
+
+        self.handle_double_click(false, Instant::now());
+        self.last_click = None;
+    }
+
+    /// Gathers the input of `event` for the next frame. Returns whether the event was consumed by
+    /// the UI, based on what it wanted during the last frame, in which case the application should
+    /// not act on it. A button or key release is consumed only if its press was, so the
+    /// application always sees both or neither.
+    pub fn handle_event<T>(&mut self, context: &Nuklear, event: &Event<T>) -> bool {
         match event {
             Event::WindowEvent { event, .. } => match event {
                 WindowEvent::CursorMoved { position, .. } => {
-                    self.last_mouse_position = position.to_logical(1.0);
-                    context.input_motion(
+                    self.last_mouse_position = position.to_logical(self.scale_factor);
+                    self.pending.push(PendingInput::Motion(
                         self.last_mouse_position.x as i32,
                         self.last_mouse_position.y as i32,
-                    );
-                }
-                WindowEvent::MouseInput { state, button, .. } => context.input_button(
-                    self.last_mouse_position.x as i32,
-                    self.last_mouse_position.y as i32,
-                    winit_to_nk_button(*button),
-                    *state == ElementState::Pressed,
-                ),
-                _ => {}
+                    ));
+
+                    context.wants_mouse()
+                }
+                WindowEvent::MouseInput { state, button, .. } => {
+                    let pressed = *state == ElementState::Pressed;
+                    let consumed =
+                        track_consumed(&mut self.buttons_consumed, *button, pressed, || {
+                            context.wants_mouse()
+                        });
+
+                    if let Some(button) = winit_to_nk_button(*button) {
+                        self.pending.push(PendingInput::Button(
+                            button,
+                            self.last_mouse_position.x as i32,
+                            self.last_mouse_position.y as i32,
+                            pressed,
+                        ));
+
+                        if button == Button::Left {
+                            self.handle_double_click(pressed, Instant::now());
+                        }
+                    }
+
+                    consumed
+                }
+                WindowEvent::MouseWheel { delta, .. } => {
+                    let (x, y) = match delta {
+                        MouseScrollDelta::LineDelta(x, y) => (*x, *y),
+                        MouseScrollDelta::PixelDelta(delta) => (
+                            (delta.x / PIXELS_PER_SCROLL_LINE) as f32,
+                            (delta.y / PIXELS_PER_SCROLL_LINE) as f32,
+                        ),
+                    };
+                    self.pending.push(PendingInput::Scroll(x, y));
+
+                    context.wants_mouse()
+                }
+                WindowEvent::Focused(false) => {
+                    self.handle_focus_lost();
+                    false
+                }
+                WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
+                    self.scale_factor = *scale_factor;
+                    false
+                }
+                WindowEvent::ModifiersChanged(modifiers) => {
+                    // Modifiers are state shared with the application, so they are never consumed
+                    self.handle_modifiers(*modifiers);
+                    false
+                }
+                WindowEvent::KeyboardInput {
+                    input:
+                        KeyboardInput {
+                            virtual_keycode: Some(keycode),
+                            state,
+                            ..
+                        },
+                    ..
+                } => {
+                    let pressed = *state == ElementState::Pressed;
+                    let consumed =
+                        track_consumed(&mut self.keys_consumed, *keycode, pressed, || {
+                            context.wants_keyboard()
+                        });
+
+                    self.handle_key(*keycode, pressed);
+                    consumed
+                }
+                WindowEvent::ReceivedCharacter(c) => {
+                    self.handle_char(*c);
+                    context.wants_keyboard()
+                }
+                _ => false,
             },
-            _ => {} // ignore
+            _ => false, // ignore
         }
     }
 
-    pub fn finish(&mut self, context: &mut Nuklear) {}
+    /// Feeds all input gathered since the last call to the context and updates the window's cursor
+    /// to the one the last frame wanted. Call this once per frame, before building the UI.
+    pub fn finish(&mut self, context: &mut Nuklear, window: &Window) {
+        let cursor = (context.cursor(), context.draws_cursor());
+        if self.cursor != Some(cursor) {
+            window.set_cursor_icon(nk_to_winit_cursor(cursor.0));
+            window.set_cursor_visible(!cursor.1);
+            self.cursor = Some(cursor);
+        }
+
+        let mut input = context.begin_input();
+
+        for pending in self.pending.drain(..) {
+            match pending {
+                PendingInput::Motion(x, y) => input.input_motion(x, y),
+                PendingInput::Button(button, x, y, down) => input.input_button(x, y, button, down),
+                PendingInput::Scroll(x, y) => input.input_scroll((x, y)),
+                PendingInput::Key(key, down) => input.input_key(key, down),
+                PendingInput::Char(c) => input.input_unicode(c),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn double_click_handler() -> NuklyWindowEventHandler {
+        NuklyWindowEventHandler::default()
+            .with_double_click_time(Duration::from_millis(500))
+            .with_double_click_distance(4.0)
+    }
+
+    fn click(handler: &mut NuklyWindowEventHandler, x: f64, y: f64, at: Instant) {
+        handler.last_mouse_position = LogicalPosition { x, y };
+        handler.handle_double_click(true, at);
+        handler.handle_double_click(false, at);
+    }
+
+    // The states of the double click button, in the order they were sent
+    fn doubles(handler: &NuklyWindowEventHandler) -> Vec<bool> {
+        handler
+            .pending
+            .iter()
+            .filter_map(|pending| match pending {
+                PendingInput::Button(Button::Double, _, _, down) => Some(*down),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn keys(handler: &NuklyWindowEventHandler) -> Vec<(Key, bool)> {
+        handler
+            .pending
+            .iter()
+            .filter_map(|pending| match pending {
+                PendingInput::Key(key, down) => Some((*key, *down)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn chars(handler: &NuklyWindowEventHandler) -> String {
+        handler
+            .pending
+            .iter()
+            .filter_map(|pending| match pending {
+                PendingInput::Char(c) => Some(*c),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn releases_follow_presses() {
+        let mut buttons = HashMap::default();
+        let mut button =
+            |pressed, wants| track_consumed(&mut buttons, MouseButton::Left, pressed, || wants);
+
+        // Pressed over the application, released over the UI
+        assert!(!button(true, false));
+        assert!(!button(false, true));
+
+        // Pressed over the UI, released over the application
+        assert!(button(true, true));
+        assert!(button(false, false));
+
+        let mut keys = HashMap::default();
+        let mut key =
+            |pressed, wants| track_consumed(&mut keys, VirtualKeyCode::A, pressed, || wants);
+
+        // Repeats while the UI starts wanting the keyboard
+        assert!(!key(true, false));
+        assert!(!key(true, true));
+        assert!(!key(false, true));
+
+        // Releases of presses that were never seen are passed on
+        assert!(!key(false, true));
+    }
+
+    #[test]
+    fn key_mapping() {
+        let none = ModifiersState::empty();
+        let ctrl = ModifiersState::CTRL;
+        let ctrl_shift = ModifiersState::CTRL | ModifiersState::SHIFT;
+
+        assert_eq!(
+            winit_to_nk_key(VirtualKeyCode::Z, ctrl),
+            Some(Key::TextUndo)
+        );
+        assert_eq!(
+            winit_to_nk_key(VirtualKeyCode::Z, ctrl_shift),
+            Some(Key::TextRedo)
+        );
+        assert_eq!(winit_to_nk_key(VirtualKeyCode::Z, none), None);
+
+        assert_eq!(
+            winit_to_nk_key(VirtualKeyCode::Home, ctrl),
+            Some(Key::TextStart)
+        );
+        assert_eq!(
+            winit_to_nk_key(VirtualKeyCode::Home, none),
+            Some(Key::TextLineStart)
+        );
+
+        // Keys without a shortcut are still sent while control is held
+        assert_eq!(
+            winit_to_nk_key(VirtualKeyCode::Delete, ctrl),
+            Some(Key::Delete)
+        );
+        assert_eq!(winit_to_nk_key(VirtualKeyCode::Up, ctrl), Some(Key::Up));
+    }
+
+    #[test]
+    fn char_filtering() {
+        let mut handler = NuklyWindowEventHandler::default();
+
+        handler.handle_char('a');
+        handler.handle_char('\r');
+        handler.handle_char('\u{8}');
+
+        handler.handle_modifiers(ModifiersState::CTRL);
+        handler.handle_char('c');
+
+        // AltGr
+        handler.handle_modifiers(ModifiersState::CTRL | ModifiersState::ALT);
+        handler.handle_char('@');
+
+        assert_eq!(chars(&handler), "a@");
+    }
+
+    #[test]
+    fn keys_released_on_focus_lost() {
+        let mut handler = NuklyWindowEventHandler::default();
+
+        handler.handle_modifiers(ModifiersState::CTRL | ModifiersState::SHIFT);
+        handler.handle_key(VirtualKeyCode::Z, true);
+        handler.pending.clear();
+
+        handler.handle_focus_lost();
+
+        let released = keys(&handler);
+        assert_eq!(released.len(), 3);
+        for key in &[Key::TextRedo, Key::Shift, Key::Ctrl] {
+            assert!(released.contains(&(*key, false)));
+        }
+        assert!(handler.keys_down.is_empty());
+        assert_eq!(handler.modifiers, ModifiersState::empty());
+    }
+
+    #[test]
+    fn double_click() {
+        let mut handler = double_click_handler();
+        let start = Instant::now();
+
+        click(&mut handler, 10.0, 10.0, start);
+        click(&mut handler, 12.0, 10.0, start + Duration::from_millis(200));
+
+        assert_eq!(doubles(&handler), vec![true, false]);
+    }
+
+    #[test]
+    fn double_click_too_far() {
+        let mut handler = double_click_handler();
+        let start = Instant::now();
+
+        click(&mut handler, 10.0, 10.0, start);
+        click(&mut handler, 20.0, 10.0, start + Duration::from_millis(200));
+
+        assert!(doubles(&handler).is_empty());
+    }
+
+    #[test]
+    fn double_click_too_slow() {
+        let mut handler = double_click_handler();
+        let start = Instant::now();
+
+        click(&mut handler, 10.0, 10.0, start);
+        click(&mut handler, 10.0, 10.0, start + Duration::from_millis(600));
+
+        assert!(doubles(&handler).is_empty());
+    }
+
+    #[test]
+    fn third_click_starts_anew() {
+        let mut handler = double_click_handler();
+        let start = Instant::now();
+
+        for i in 0..3 {
+            click(
+                &mut handler,
+                10.0,
+                10.0,
+                start + Duration::from_millis(100 * i),
+            );
+        }
+        assert_eq!(doubles(&handler), vec![true, false]);
+
+        click(&mut handler, 10.0, 10.0, start + Duration::from_millis(300));
+        assert_eq!(doubles(&handler), vec![true, false, true, false]);
+    }
+
+    #[test]
+    fn double_click_released_on_focus_lost() {
+        let mut handler = double_click_handler();
+        let start = Instant::now();
+
+        click(&mut handler, 10.0, 10.0, start);
+        handler.handle_double_click(true, start + Duration::from_millis(200));
+        handler.handle_focus_lost();
+
+        assert_eq!(doubles(&handler), vec![true, false]);
+    }
 }